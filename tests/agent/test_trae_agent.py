@@ -3,7 +3,7 @@
 
 import asyncio
 import unittest
-from unittest.mock import MagicMock, patch
+from unittest.mock import AsyncMock, MagicMock, patch
 
 from trae_agent.agent.agent_basics import AgentError
 from trae_agent.agent.trae_agent import TraeAgent
@@ -83,7 +83,7 @@ class TestTraeAgentExtended(unittest.TestCase):
 
         self.assertEqual(self.agent.project_path, self.test_project_path)
         self.assertEqual(self.agent.must_patch, "true")
-        self.assertEqual(len(self.agent.tools), 5)
+        self.assertEqual(len(self.agent.tools), 15)
         self.assertTrue(any(tool.get_name() == "bash" for tool in self.agent.tools))
 
     @patch("subprocess.check_output")
@@ -115,6 +115,12 @@ class TestTraeAgentExtended(unittest.TestCase):
         asyncio.run(self.agent.execute_task())
         mock_console.start.assert_called_once()
 
+    def test_execute_task_closes_tools(self):
+        self.agent.new_task("test", {"project_path": self.test_project_path}, ["bash"])
+        with patch.object(self.agent.tools[0], "aclose", new_callable=AsyncMock) as mock_aclose:
+            asyncio.run(self.agent.execute_task())
+        mock_aclose.assert_awaited_once()
+
     def test_task_completion_detection(self):
         mock_response = MagicMock(spec=LLMResponse)
 
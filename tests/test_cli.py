@@ -1,9 +1,18 @@
+import json
+import os
+import signal
 import unittest
+from pathlib import Path
 from unittest.mock import patch
 
 from click.testing import CliRunner
 
-from trae_agent.cli import cli
+from trae_agent.agent.agent_basics import AgentExecution, AgentState, AgentStep
+from trae_agent.cli import cli, create_agent
+from trae_agent.tools.base import ToolCall, ToolResult
+from trae_agent.tools.run import MAX_RESPONSE_LEN, truncate_middle
+from trae_agent.utils.audit_log import AuditLog
+from trae_agent.utils.trajectory_recorder import TrajectoryRecorder
 
 
 class TestCli(unittest.TestCase):
@@ -21,6 +30,16 @@ class TestCli(unittest.TestCase):
         call_args, _ = mock_create_agent.return_value.new_task.call_args
         self.assertEqual(call_args[0], long_prompt)
 
+    @patch("trae_agent.cli.create_agent")
+    @patch("trae_agent.cli.asyncio.run")
+    def test_run_dry_run_sets_config_not_environment(self, mock_asyncio_run, mock_create_agent):
+        with patch.dict(os.environ):
+            result = self.runner.invoke(cli, ["run", "task", "--dry-run"])
+            self.assertNotIn("TRAE_DRY_RUN", os.environ)
+        self.assertEqual(result.exit_code, 0)
+        config = mock_create_agent.call_args.args[0]
+        self.assertTrue(config.dry_run)
+
     @patch("trae_agent.cli.create_agent")
     @patch("trae_agent.cli.asyncio.run")
     def test_run_with_file_argument(self, mock_asyncio_run, mock_create_agent):
@@ -91,6 +110,263 @@ class TestCli(unittest.TestCase):
         self.assertNotEqual(result.exit_code, 0)
         self.assertIn("Unexpected error: Core agent failed", result.output)
 
+    @patch("trae_agent.cli.create_agent")
+    @patch("trae_agent.cli.asyncio.run", side_effect=KeyboardInterrupt)
+    def test_run_interrupt_runs_shutdown_hooks(self, mock_asyncio_run, mock_create_agent):
+        """Test that an interrupt closes the tools, saves the trajectory and restores handlers."""
+        previous_handler = signal.getsignal(signal.SIGTERM)
+        mock_agent = mock_create_agent.return_value
+        mock_agent.setup_trajectory_recording.return_value = "trajectories/trajectory_1.json"
+
+        result = self.runner.invoke(cli, ["run", "some task"])
+
+        self.assertEqual(result.exit_code, 1)
+        self.assertIn("interrupted, session saved as trajectory_1", result.output)
+        mock_agent.register_shutdown_hooks.assert_called_once()
+        mock_agent.trajectory_recorder.finalize_recording.assert_called_once_with(
+            success=False, final_result="Task execution interrupted by user"
+        )
+        self.assertEqual(signal.getsignal(signal.SIGTERM), previous_handler)
+
+    @patch("trae_agent.cli.load_config")
+    @patch("trae_agent.cli.create_agent")
+    @patch("trae_agent.cli.asyncio.run", side_effect=KeyboardInterrupt)
+    def test_interactive_interrupt_saves_session(
+        self, mock_asyncio_run, mock_create_agent, mock_load_config
+    ):
+        """Test that interrupting a task in interactive mode saves it and keeps the session."""
+        previous_handler = signal.getsignal(signal.SIGTERM)
+        mock_load_config.return_value.confirm_tools = []
+        mock_agent = mock_create_agent.return_value
+        mock_agent.setup_trajectory_recording.return_value = "trajectories/trajectory_1.json"
+
+        result = self.runner.invoke(cli, ["interactive"], input="some task\n/tmp\nexit\n")
+
+        self.assertEqual(result.exit_code, 0)
+        self.assertIn("interrupted, session saved as trajectory_1", result.output)
+        self.assertIn("Goodbye!", result.output)
+        mock_agent.register_shutdown_hooks.assert_called_once()
+        mock_agent.trajectory_recorder.finalize_recording.assert_called_once_with(
+            success=False, final_result="Task execution interrupted by user"
+        )
+        self.assertEqual(signal.getsignal(signal.SIGTERM), previous_handler)
+
+    @patch("trae_agent.cli.create_agent")
+    @patch("trae_agent.cli.asyncio.run")
+    def test_run_json_output(self, mock_asyncio_run, mock_create_agent):
+        """Test that --output json prints a machine-readable result and sets the exit code."""
+        mock_create_agent.return_value.setup_trajectory_recording.return_value = "traj.json"
+        step = AgentStep(
+            step_number=1,
+            state=AgentState.COMPLETED,
+            tool_calls=[ToolCall(name="bash", call_id="call_1", arguments={"command": "ls"})],
+            tool_results=[ToolResult(call_id="call_1", name="bash", success=True, result="a")],
+        )
+        mock_asyncio_run.return_value = AgentExecution(
+            task="some task", steps=[step], final_result="done", success=True
+        )
+
+        result = self.runner.invoke(cli, ["run", "some task", "--output", "json"])
+        self.assertEqual(result.exit_code, 0)
+        mock_create_agent.return_value.set_cli_console.assert_called_once_with(None)
+        summary = json.loads(result.output.strip().splitlines()[-1])
+        self.assertTrue(summary["success"])
+        self.assertEqual(summary["final_result"], "done")
+        self.assertEqual(summary["tool_calls"], [{"step": 1, "name": "bash", "success": True}])
+        self.assertEqual(summary["trajectory_file"], "traj.json")
+
+        mock_asyncio_run.return_value = AgentExecution(task="some task", steps=[], success=False)
+        result = self.runner.invoke(cli, ["run", "some task", "--output", "json"])
+        self.assertEqual(result.exit_code, 1)
+        self.assertFalse(json.loads(result.output.strip().splitlines()[-1])["success"])
+
+        result = self.runner.invoke(cli, ["run", "some task"])
+        self.assertEqual(result.exit_code, 0)
+
+    @patch("trae_agent.cli.create_agent")
+    def test_run_json_output_for_setup_errors(self, mock_create_agent):
+        """Test that --output json prints a JSON result for errors before the task starts."""
+        mock_create_agent.return_value.setup_trajectory_recording.return_value = "traj.json"
+
+        def last_json_line(args: list[str]) -> dict[str, object]:
+            result = self.runner.invoke(cli, ["run", *args, "--output", "json"])
+            self.assertEqual(result.exit_code, 1)
+            summary = json.loads(result.output.strip().splitlines()[-1])
+            self.assertFalse(summary["success"])
+            return summary
+
+        summary = last_json_line(["some task", "--file", "task.txt"])
+        self.assertEqual(
+            summary["error"], "Error: Cannot use both a task string and the --file argument."
+        )
+        summary = last_json_line(["--file", "nonexistent.txt"])
+        self.assertEqual(summary["error"], "Error: File not found: nonexistent.txt")
+        summary = last_json_line(["some task", "--working-dir", "/nonexistent/dir"])
+        self.assertIn("Error changing directory", str(summary["error"]))
+        self.assertEqual(summary["trajectory_file"], "traj.json")
+
+        with patch("trae_agent.cli.TraeAgent", side_effect=ValueError("no API key")):
+            mock_create_agent.side_effect = create_agent
+            summary = last_json_line(["some task"])
+        self.assertEqual(summary["error"], "Error creating agent: no API key")
+
+    def test_replay_reports_identical_and_differing_output(self):
+        """Test that replay re-executes recorded calls and diffs the fresh output."""
+        with self.runner.isolated_filesystem():
+            with open("tool_calls.jsonl", "w") as f:
+                for record in [
+                    {"name": "task_done", "arguments": {}, "result": "Task done.", "error": None},
+                    {"name": "task_done", "arguments": {}, "result": "Stale output", "error": None},
+                ]:
+                    f.write(json.dumps(record) + "\n")
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl"])
+            self.assertNotEqual(result.exit_code, 0)
+            self.assertIn("#1 task_done: identical", result.output)
+            self.assertIn("#2 task_done: output differs", result.output)
+            self.assertIn("+Task done.", result.output)
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl", "--to", "1"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertIn("1/1 tool calls matched", result.output)
+
+    def test_replay_rejects_malformed_records(self):
+        """Test that a record without arguments is reported instead of raising."""
+        with self.runner.isolated_filesystem():
+            with open("tool_calls.jsonl", "w") as f:
+                f.write(json.dumps({"name": "bash", "result": "hi"}) + "\n")
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl"])
+            self.assertEqual(result.exit_code, 1)
+            self.assertIsInstance(result.exception, SystemExit)
+            self.assertIn("Could not read tool calls", result.output)
+
+    def test_replay_truncates_like_the_recorded_run(self):
+        """Test that replay applies the max_tool_output budget the recorded results went through."""
+        output = "\n".join(str(i) for i in range(1, 20001))
+        record = {
+            "name": "bash",
+            "arguments": {"command": "seq 1 20000"},
+            "result": truncate_middle(output, MAX_RESPONSE_LEN),
+            "error": "",
+        }
+        with self.runner.isolated_filesystem():
+            with open("tool_calls.jsonl", "w") as f:
+                f.write(json.dumps(record) + "\n")
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertIn("1/1 tool calls matched", result.output)
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl", "--max-output", "100"])
+            self.assertNotEqual(result.exit_code, 0)
+            self.assertIn("#1 bash: output differs", result.output)
+
+    def test_replay_asks_before_confirm_tools(self):
+        """Test that replay asks before calls to confirm_tools unless --yes is given."""
+        record = {"name": "bash", "arguments": {"command": "touch marker"}, "result": ""}
+        with self.runner.isolated_filesystem():
+            with open("trae_config.json", "w") as f:
+                json.dump({"confirm_tools": ["bash"]}, f)
+            with open("tool_calls.jsonl", "w") as f:
+                f.write(json.dumps(record) + "\n")
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl"], input="n\n")
+            self.assertNotEqual(result.exit_code, 0)
+            self.assertIn("Confirm tool call: bash", result.output)
+            self.assertIn("The user declined", result.output)
+            self.assertFalse(Path("marker").exists())
+
+            result = self.runner.invoke(cli, ["replay", "tool_calls.jsonl", "--yes"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertNotIn("Confirm tool call", result.output)
+            self.assertTrue(Path("marker").exists())
+
+    def test_audit_tail_and_grep(self):
+        """Test that audit tail and grep print entries of the configured audit log."""
+        with self.runner.isolated_filesystem():
+            with open("trae_config.json", "w") as f:
+                json.dump({"audit_log": "audit.jsonl"}, f)
+            audit_log = AuditLog("audit.jsonl")
+            for command in ["ls", "git status", "make test"]:
+                audit_log.record(
+                    ToolCall(name="bash", call_id="call_1", arguments={"command": command}),
+                    ToolResult(call_id="call_1", name="bash", success=True),
+                    session_id="trajectory_1",
+                )
+
+            result = self.runner.invoke(cli, ["audit", "tail", "-n", "2"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertNotIn('"ls"', result.output)
+            self.assertIn('trajectory_1 ok bash {"command": "git status"}', result.output)
+            self.assertIn("make test", result.output)
+
+            result = self.runner.invoke(cli, ["audit", "grep", "git|make"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertEqual(len(result.output.strip().splitlines()), 2)
+
+            result = self.runner.invoke(cli, ["audit", "grep", "("])
+            self.assertNotEqual(result.exit_code, 0)
+            self.assertIn("Invalid pattern", result.output)
+
+    def test_export_md_writes_markdown_next_to_trajectory(self):
+        """Test that export-md converts a trajectory file to Markdown."""
+        with self.runner.isolated_filesystem():
+            recorder = TrajectoryRecorder("trajectory.json")
+            recorder.start_recording(
+                task="test task", provider="anthropic", model="test-model", max_steps=5
+            )
+
+            result = self.runner.invoke(cli, ["export-md", "trajectory.json"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertIn("## User", Path("trajectory.md").read_text())
+
+            result = self.runner.invoke(cli, ["export-md", "missing.json"])
+            self.assertNotEqual(result.exit_code, 0)
+            self.assertIn("File not found", result.output)
+
+    def test_search_finds_messages_across_trajectories(self):
+        """Test that search matches trajectory messages and honors the filters."""
+        with self.runner.isolated_filesystem():
+            for name, task in [("first", "Fix the login bug"), ("second", "Add a README")]:
+                recorder = TrajectoryRecorder(f"trajectories/{name}.json")
+                recorder.start_recording(
+                    task=task, provider="anthropic", model="test-model", max_steps=5
+                )
+
+            result = self.runner.invoke(cli, ["search", "LOGIN"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertIn("first", result.output)
+            self.assertIn("[user]", result.output)
+            self.assertNotIn("second", result.output)
+            self.assertIn("1 matching message", result.output)
+
+            result = self.runner.invoke(cli, ["search", "login", "--role", "assistant"])
+            self.assertIn("0 matching messages", result.output)
+
+            result = self.runner.invoke(cli, ["search", "login", "--until", "2000-01-01"])
+            self.assertIn("0 matching messages", result.output)
+
+            recorder = TrajectoryRecorder("trajectories/undated.json")
+            recorder.start_recording(
+                task="Another login issue", provider="anthropic", model="test-model", max_steps=5
+            )
+            recorder.trajectory_data["start_time"] = None
+            recorder.save_trajectory()
+
+            result = self.runner.invoke(cli, ["search", "login", "--since", "2000-01-01"])
+            self.assertEqual(result.exit_code, 0)
+            self.assertNotIn("undated", result.output)
+            self.assertIn("1 matching message", result.output)
+
+            result = self.runner.invoke(cli, ["search", "login"])
+            self.assertIn("2 matching messages", result.output)
+
+            Path("trajectories/latin1.json").write_bytes(b'{"task": "caf\xe9"}')
+            result = self.runner.invoke(cli, ["search", "..."])
+            self.assertEqual(result.exit_code, 0)
+            self.assertIn("0 matching messages", result.output)
+
 
 if __name__ == "__main__":
     unittest.main()
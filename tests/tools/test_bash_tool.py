@@ -1,7 +1,10 @@
 # Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
 # SPDX-License-Identifier: MIT
 
+import asyncio
+import os
 import unittest
+from unittest.mock import patch
 
 from trae_agent.tools.base import ToolCallArguments
 from trae_agent.tools.bash_tool import BashTool
@@ -58,6 +61,94 @@ class TestBashTool(unittest.IsolatedAsyncioTestCase):
         self.assertIn("hello world", result.output)
         self.assertEqual(result.error, "")
 
+    async def assert_process_exits(self, pid: int):
+        for _ in range(50):
+            try:
+                os.kill(pid, 0)
+            except ProcessLookupError:
+                return
+            await asyncio.sleep(0.1)
+        self.fail("background process survived closing the bash tool")
+
+    @unittest.skipIf(os.name == "nt", "process groups are not used on Windows")
+    async def test_aclose_kills_background_processes(self):
+        result = await self.tool.execute(ToolCallArguments({"command": "sleep 60 & echo $!"}))
+        pid = int(result.output.strip())
+
+        await self.tool.aclose()
+        self.assertIsNone(self.tool._session)
+        await self.assert_process_exits(pid)
+
+    @unittest.skipIf(os.name == "nt", "process groups are not used on Windows")
+    async def test_aclose_kills_background_processes_ignoring_sigterm(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"command": "(trap '' TERM; sleep 60) & echo $!"})
+        )
+        pid = int(result.output.strip())
+
+        await self.tool.aclose()
+        self.assertIsNone(self.tool._session)
+        await self.assert_process_exits(pid)
+
+    async def test_dry_run_does_not_execute(self):
+        result = await self.tool.dry_run(
+            ToolCallArguments({"command": "touch /tmp/should_not_exist"})
+        )
+
+        self.assertEqual(result.error_code, 0)
+        self.assertIn("[DRY RUN] no changes applied.", result.output)
+        self.assertIn("touch /tmp/should_not_exist", result.output)
+
+    def test_is_read_only(self):
+        for command in ["ls -la", "git status", "grep -rn foo src | head", "find . -name '*.py'"]:
+            self.assertTrue(self.tool.is_read_only(ToolCallArguments({"command": command})))
+        for command in [
+            "rm -rf build",
+            "ls; rm x",
+            "cat a > b",
+            "echo $(touch x)",
+            "find . -delete",
+            "git commit -m x",
+            "ls &",
+        ]:
+            self.assertFalse(self.tool.is_read_only(ToolCallArguments({"command": command})))
+
+    @unittest.skipIf(os.name == "nt", "cwd and env are only supported with bash")
+    async def test_cwd_and_env_apply_to_single_command(self):
+        result = await self.tool.execute(
+            ToolCallArguments(
+                {"command": 'pwd; echo "$GREETING"', "cwd": "/", "env": ["GREETING=hi there"]}
+            )
+        )
+        self.assertEqual(result.output, "/\nhi there")
+
+        result = await self.tool.execute(ToolCallArguments({"command": 'echo "[$GREETING]"'}))
+        self.assertEqual(result.output, "[]")
+
+    @unittest.skipIf(os.name == "nt", "cwd and env are only supported with bash")
+    @patch.dict(os.environ, {"TRAE_TEST_SECRET": "secret", "TRAE_TEST_KEPT": "kept"})
+    async def test_clean_env_keeps_only_allowlist_and_explicit_env(self):
+        result = await self.tool.execute(
+            ToolCallArguments(
+                {
+                    "command": 'echo "$TRAE_TEST_SECRET|$TRAE_TEST_KEPT|$EXTRA|${PATH:+path}"',
+                    "clean_env": True,
+                    "env_allowlist": ["TRAE_TEST_KEPT"],
+                    "env": ["EXTRA=extra"],
+                }
+            )
+        )
+        self.assertEqual(result.output, "|kept|extra|path")
+
+    async def test_invalid_cwd_and_env(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"command": "true", "cwd": "/does/not/exist"})
+        )
+        self.assertIn("is not a directory", result.error)
+
+        result = await self.tool.execute(ToolCallArguments({"command": "true", "env": ["1X"]}))
+        self.assertIn("Invalid `env` entry", result.error)
+
     async def test_missing_command_handling(self):
         result = await self.tool.execute(ToolCallArguments({}))
         self.assertIn("no command provided", result.error.lower())
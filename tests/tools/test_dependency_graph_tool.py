@@ -0,0 +1,135 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import json
+import shutil
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.dependency_graph_tool import DependencyGraphTool
+
+
+class TestDependencyGraphTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = DependencyGraphTool()
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.root = Path(self.temp_dir.name)
+
+    async def graph(self, path: Path, **arguments: object):
+        return await self.tool.execute(ToolCallArguments({"path": str(path), **arguments}))
+
+    def write_crate(self, name: str, dependencies: str = "", dev_dependencies: str = ""):
+        crate = self.root / name
+        (crate / "src").mkdir(parents=True)
+        (crate / "src" / "lib.rs").write_text("")
+        (crate / "Cargo.toml").write_text(
+            f'[package]\nname = "{name}"\nversion = "0.1.0"\nedition = "2021"\n\n'
+            f"[dependencies]\n{dependencies}\n[dev-dependencies]\n{dev_dependencies}"
+        )
+        return crate
+
+    @unittest.skipIf(shutil.which("cargo") is None, "cargo is not installed")
+    async def test_cargo_project(self):
+        self.write_crate("leaf")
+        self.write_crate("util", dependencies='leaf = { path = "../leaf" }\n')
+        self.write_crate("testkit")
+        app = self.write_crate(
+            "app",
+            dependencies='util = { path = "../util" }\n',
+            dev_dependencies='testkit = { path = "../testkit" }\n',
+        )
+
+        result = await self.graph(app)
+
+        self.assertIsNone(result.error)
+        output = result.output or ""
+        self.assertIn("Cargo project", output)
+        self.assertIn("2 unique packages", output)
+        self.assertIn('"app 0.1.0" -> "util 0.1.0";', output)
+        self.assertIn('"util 0.1.0" -> "leaf 0.1.0";', output)
+        self.assertNotIn("testkit", output)
+
+        result = await self.graph(app, depth=1, include_dev=True)
+
+        output = result.output or ""
+        self.assertIn("2 unique packages", output)
+        self.assertIn('"app 0.1.0" -> "testkit 0.1.0";', output)
+        self.assertNotIn("leaf", output)
+
+    @unittest.skipIf(shutil.which("npm") is None, "npm is not installed")
+    async def test_npm_project(self):
+        def write_package(directory: Path, manifest: dict[str, object]):
+            directory.mkdir(parents=True, exist_ok=True)
+            (directory / "package.json").write_text(json.dumps(manifest))
+
+        write_package(
+            self.root,
+            {
+                "name": "web",
+                "version": "1.0.0",
+                "dependencies": {"left": "^1.0.0"},
+                "devDependencies": {"lint": "^2.0.0"},
+            },
+        )
+        modules = self.root / "node_modules"
+        write_package(
+            modules / "left",
+            {"name": "left", "version": "1.2.0", "dependencies": {"pad": "^3.0.0"}},
+        )
+        write_package(modules / "pad", {"name": "pad", "version": "3.0.1"})
+        write_package(modules / "lint", {"name": "lint", "version": "2.0.0"})
+
+        result = await self.graph(self.root)
+
+        self.assertIsNone(result.error)
+        output = result.output or ""
+        self.assertIn("npm project", output)
+        self.assertIn("2 unique packages", output)
+        self.assertIn('"web 1.0.0" -> "left 1.2.0";', output)
+        self.assertIn('"left 1.2.0" -> "pad 3.0.1";', output)
+        self.assertNotIn("lint", output)
+
+        result = await self.graph(self.root, depth=1, include_dev=True)
+
+        output = result.output or ""
+        self.assertIn('"web 1.0.0" -> "lint 2.0.0";', output)
+        self.assertNotIn("pad", output)
+
+    @unittest.skipIf(shutil.which("pip") is None, "pip is not installed")
+    async def test_pip_project(self):
+        (self.root / "pyproject.toml").write_text(
+            '[project]\nname = "tool"\nversion = "0.2.0"\n'
+            'dependencies = ["pip>=20", "no-such-package-for-trae-tests; os_name != \'nt\'"]\n'
+            '\n[project.optional-dependencies]\ndev = ["another-missing-package"]\n'
+        )
+
+        result = await self.graph(self.root)
+
+        self.assertIsNone(result.error)
+        output = result.output or ""
+        self.assertIn("pip project", output)
+        self.assertIn("2 unique packages", output)
+        self.assertRegex(output, r'"tool 0\.2\.0" -> "pip [0-9.]+";')
+        self.assertIn('"tool 0.2.0" -> "no-such-package-for-trae-tests (not installed)";', output)
+        self.assertNotIn("another-missing-package", output)
+
+        result = await self.graph(self.root, include_dev=True)
+
+        self.assertIn("another-missing-package (not installed)", result.output or "")
+
+    async def test_invalid_arguments(self):
+        result = await self.graph(Path("relative"))
+        self.assertIn("not an absolute path", result.error or "")
+
+        result = await self.graph(self.root)
+        self.assertIn("No project manifest (Cargo.toml, package.json, pyproject.toml)", result.error or "")
+
+        result = await self.graph(self.root, depth=0)
+        self.assertIn("`depth` should be a positive integer", result.error or "")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -100,6 +100,122 @@ class TestTextEditorTool(unittest.IsolatedAsyncioTestCase):
         self.mock_write.assert_called_once()
         self.assertIn("edited", result.output)
 
+    async def test_str_replace_dry_run(self):
+        self.mock_file_system(content="old_content\nline2")
+        result = await self.tool.dry_run(
+            ToolCallArguments(
+                {
+                    "command": "str_replace",
+                    "path": str(self.test_file),
+                    "old_str": "old_content",
+                    "new_str": "new_content",
+                }
+            )
+        )
+        self.mock_write.assert_not_called()
+        self.assertIn("[DRY RUN] no changes applied.", result.output)
+        self.assertIn("-old_content\n+new_content", result.output)
+
+    async def test_dry_run_diff_shows_expanded_tabs(self):
+        self.mock_file_system(content="\tfirst\n\tsecond")
+        result = await self.tool.dry_run(
+            ToolCallArguments(
+                {
+                    "command": "insert",
+                    "path": str(self.test_file),
+                    "insert_line": 2,
+                    "new_str": "third",
+                }
+            )
+        )
+        self.mock_write.assert_not_called()
+        self.assertIn("-\tfirst\n-\tsecond\n+        first\n+        second\n+third", result.output)
+
+    async def test_create_file_dry_run(self):
+        self.mock_file_system(exists=False)
+        result = await self.tool.dry_run(
+            ToolCallArguments(
+                {
+                    "command": "create",
+                    "path": str(self.test_file),
+                    "file_text": "new content",
+                }
+            )
+        )
+        self.mock_write.assert_not_called()
+        self.assertIn("--- /dev/null", result.output)
+        self.assertIn("+new content", result.output)
+
+    async def test_str_replace_rejects_external_modification(self):
+        self.mock_file_system(content="old_content\nline2")
+        _ = await self.tool.execute(
+            ToolCallArguments({"command": "view", "path": str(self.test_file)})
+        )
+        self.mock_read.return_value = "old_content\nline2 changed elsewhere"
+
+        arguments = {
+            "command": "str_replace",
+            "path": str(self.test_file),
+            "old_str": "old_content",
+            "new_str": "new_content",
+        }
+        result = await self.tool.execute(ToolCallArguments(arguments))
+        self.mock_write.assert_not_called()
+        self.assertIn("modified since it was last read", result.error)
+        self.assertIn("+line2 changed elsewhere", result.error)
+
+        result = await self.tool.execute(ToolCallArguments({**arguments, "force": True}))
+        self.mock_write.assert_called_once_with("new_content\nline2 changed elsewhere")
+
+    async def test_external_modification_detected_across_path_spellings(self):
+        self.mock_file_system(content="old_content\nline2")
+        other_spelling = f"{self.test_dir}/../test_dir/test_file.txt"
+        _ = await self.tool.execute(ToolCallArguments({"command": "view", "path": other_spelling}))
+        self.mock_read.return_value = "old_content\nline2 changed elsewhere"
+
+        result = await self.tool.execute(
+            ToolCallArguments(
+                {
+                    "command": "str_replace",
+                    "path": str(self.test_file),
+                    "old_str": "old_content",
+                    "new_str": "new_content",
+                }
+            )
+        )
+        self.mock_write.assert_not_called()
+        self.assertIn("modified since it was last read", result.error)
+
+    async def test_consecutive_edits_are_not_treated_as_external(self):
+        self.mock_file_system(content="line1\nline3")
+        _ = await self.tool.execute(
+            ToolCallArguments({"command": "view", "path": str(self.test_file)})
+        )
+        _ = await self.tool.execute(
+            ToolCallArguments(
+                {
+                    "command": "insert",
+                    "path": str(self.test_file),
+                    "insert_line": 1,
+                    "new_str": "line2",
+                }
+            )
+        )
+        self.mock_read.return_value = "line1\nline2\nline3"
+
+        result = await self.tool.execute(
+            ToolCallArguments(
+                {
+                    "command": "str_replace",
+                    "path": str(self.test_file),
+                    "old_str": "line3",
+                    "new_str": "line4",
+                }
+            )
+        )
+        self.assertIsNone(result.error)
+        self.assertEqual(self.mock_write.call_count, 2)
+
     async def test_view_directory(self):
         self.mock_file_system(exists=True, is_dir=True)
         with patch("trae_agent.tools.edit_tool.run", new_callable=AsyncMock) as mock_run:
@@ -109,6 +225,55 @@ class TestTextEditorTool(unittest.IsolatedAsyncioTestCase):
             )
         self.assertIn("files and directories", result.output)
 
+    async def test_view_directory_quotes_path(self):
+        self.mock_file_system(exists=True, is_dir=True)
+        with patch("trae_agent.tools.edit_tool.run", new_callable=AsyncMock) as mock_run:
+            mock_run.return_value = (0, "file1", "")
+            await self.tool.execute(
+                ToolCallArguments({"command": "view", "path": "/tmp/my dir; touch x"})
+            )
+        self.assertTrue(mock_run.call_args.args[0].startswith("find '/tmp/my dir; touch x' "))
+
+    async def test_view_directory_paginated(self):
+        self.mock_file_system(exists=True, is_dir=True)
+        listing = "\n".join(f"{self.test_dir}/file{i}" for i in range(5))
+        with patch("trae_agent.tools.edit_tool.run", new_callable=AsyncMock) as mock_run:
+            mock_run.return_value = (0, listing, "")
+            first_page = await self.tool.execute(
+                ToolCallArguments({"command": "view", "path": str(self.test_dir), "page_size": 3})
+            )
+            last_page = await self.tool.execute(
+                ToolCallArguments(
+                    {
+                        "command": "view",
+                        "path": str(self.test_dir),
+                        "page_size": 3,
+                        "page_token": "3",
+                    }
+                )
+            )
+        self.assertIn("file2", first_page.output)
+        self.assertNotIn("file3", first_page.output)
+        self.assertIn(
+            'Showing entries 1-3 of 5. To see more, call `view` again with page_token "3"',
+            first_page.output,
+        )
+        self.assertIn("file4", last_page.output)
+        self.assertIn("Showing entries 4-5 of 5.]", last_page.output)
+
+    async def test_view_directory_paginated_despite_errors(self):
+        self.mock_file_system(exists=True, is_dir=True)
+        listing = "\n".join(f"{self.test_dir}/file{i}" for i in range(5))
+        denied = f"find: '{self.test_dir}/private': Permission denied"
+        with patch("trae_agent.tools.edit_tool.run", new_callable=AsyncMock) as mock_run:
+            mock_run.return_value = (1, listing, denied)
+            result = await self.tool.execute(
+                ToolCallArguments({"command": "view", "path": str(self.test_dir), "page_size": 3})
+            )
+        self.assertNotIn("file3", result.output)
+        self.assertIn("Showing entries 1-3 of 5.", result.output)
+        self.assertEqual(result.error, denied)
+
     async def test_view_file(self):
         self.mock_file_system(exists=True, is_dir=False, content="line1\nline2\nline3")
         result = await self.tool.execute(
@@ -0,0 +1,84 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import hashlib
+import os
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.file_hash_tool import FileHashTool
+
+
+class TestFileHashTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = FileHashTool()
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.root = Path(self.temp_dir.name)
+        self.file = self.root / "a.txt"
+        self.file.write_bytes(b"hello\n")
+
+    async def test_sha256_is_default(self):
+        result = await self.tool.execute(ToolCallArguments({"path": str(self.file)}))
+
+        self.assertIsNone(result.error)
+        self.assertIn(hashlib.sha256(b"hello\n").hexdigest(), result.output or "")
+        self.assertIn("(6 bytes)", result.output or "")
+
+    async def test_md5(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.file), "algorithm": "md5"})
+        )
+
+        self.assertIn(hashlib.md5(b"hello\n").hexdigest(), result.output or "")
+
+    async def test_blake2b(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.file), "algorithm": "blake2b"})
+        )
+
+        self.assertIn(hashlib.blake2b(b"hello\n").hexdigest(), result.output or "")
+
+    async def test_unsupported_algorithm(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.file), "algorithm": "blake3"})
+        )
+
+        self.assertIn("Unsupported algorithm", result.error or "")
+
+    async def test_directory_requires_manifest(self):
+        result = await self.tool.execute(ToolCallArguments({"path": str(self.root)}))
+
+        self.assertIn("is a directory", result.error or "")
+
+    @unittest.skipUnless(hasattr(os, "mkfifo"), "FIFOs are not supported on this platform")
+    async def test_rejects_special_files(self):
+        fifo = self.root / "fifo"
+        os.mkfifo(fifo)
+
+        result = await self.tool.execute(ToolCallArguments({"path": str(fifo)}))
+
+        self.assertIn("is not a regular file or directory", result.error or "")
+
+    async def test_directory_manifest_skips_hidden_files(self):
+        (self.root / "sub").mkdir()
+        (self.root / "sub" / "b.txt").write_bytes(b"b")
+        (self.root / ".hidden").write_bytes(b"secret")
+        (self.root / ".git").mkdir()
+        (self.root / ".git" / "HEAD").write_bytes(b"ref")
+
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.root), "manifest": True})
+        )
+
+        output = result.output or ""
+        self.assertIn("(2 files)", output)
+        self.assertIn("sub/b.txt", output)
+        self.assertNotIn(".hidden", output)
+        self.assertNotIn(".git", output)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,88 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import os
+import shutil
+import tempfile
+import unittest
+from pathlib import Path
+from unittest.mock import patch
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.format_code_tool import FormatCodeTool
+
+UNFORMATTED_RUST = "fn main(){let x=1;}\n"
+FORMATTED_RUST = "fn main() {\n    let x = 1;\n}\n"
+
+
+@unittest.skipIf(shutil.which("rustfmt") is None, "rustfmt is not installed")
+class TestFormatCodeTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = FormatCodeTool()
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.file = Path(self.temp_dir.name) / "main.rs"
+        self.file.write_text(UNFORMATTED_RUST)
+
+    async def test_formats_content_without_writing(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"content": UNFORMATTED_RUST, "language": "rust"})
+        )
+
+        self.assertIsNone(result.error)
+        self.assertEqual(result.output, FORMATTED_RUST)
+        self.assertTrue(self.tool.is_read_only(ToolCallArguments({"content": "x"})))
+
+    async def test_formats_file_in_place(self):
+        os.chmod(self.file, 0o600)
+
+        result = await self.tool.execute(ToolCallArguments({"path": str(self.file)}))
+
+        self.assertEqual(result.output, f"Formatted {self.file} with rustfmt.")
+        self.assertEqual(self.file.read_text(), FORMATTED_RUST)
+        self.assertEqual(self.file.stat().st_mode & 0o777, 0o600)
+
+        result = await self.tool.execute(ToolCallArguments({"path": str(self.file)}))
+        self.assertEqual(result.output, f"{self.file} is already formatted.")
+
+    async def test_dry_run(self):
+        result = await self.tool.dry_run(ToolCallArguments({"path": str(self.file)}))
+
+        self.assertIn("[DRY RUN] no changes applied.", result.output or "")
+        self.assertIn("-fn main(){let x=1;}\n+fn main() {", result.output or "")
+        self.assertEqual(self.file.read_text(), UNFORMATTED_RUST)
+
+    async def test_syntax_error(self):
+        self.file.write_text("fn main( {\n")
+
+        result = await self.tool.execute(ToolCallArguments({"path": str(self.file)}))
+
+        self.assertIn("rustfmt failed", result.error or "")
+        self.assertEqual(self.file.read_text(), "fn main( {\n")
+
+    async def test_missing_formatter(self):
+        with patch("shutil.which", return_value=None):
+            result = await self.tool.execute(
+                ToolCallArguments({"content": "package main\n", "language": "go"})
+            )
+
+        self.assertIn("go code is formatted with gofmt, which is not installed", result.error or "")
+        self.assertIn("not found on PATH: black, gofmt, prettier, rustfmt", result.error or "")
+
+    async def test_invalid_arguments(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.file), "content": "x"})
+        )
+        self.assertIn("exactly one of `path` and `content`", result.error or "")
+
+        result = await self.tool.execute(ToolCallArguments({"content": "x"}))
+        self.assertIn("`language` is required", result.error or "")
+
+        other = self.file.with_suffix(".txt")
+        other.write_text("x")
+        result = await self.tool.execute(ToolCallArguments({"path": str(other)}))
+        self.assertIn("Cannot tell the language", result.error or "")
+
+
+if __name__ == "__main__":
+    unittest.main()
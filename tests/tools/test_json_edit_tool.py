@@ -44,6 +44,28 @@ class TestJSONEditTool(unittest.IsolatedAsyncioTestCase):
         self.addCleanup(self.exists_patcher.stop)
         self.addCleanup(self.is_absolute_patcher.stop)
 
+    @patch("json.dump")
+    async def test_set_config_value_dry_run(self, mock_json_dump):
+        """Test that dry-run mode reports the change without writing the file."""
+        self.mock_file_read()
+        with patch("pathlib.Path.read_text", return_value=json.dumps(self.sample_data, indent=2)):
+            result = await self.tool.dry_run(
+                ToolCallArguments(
+                    {
+                        "operation": "set",
+                        "file_path": self.test_file_path,
+                        "json_path": "$.config.enabled",
+                        "value": False,
+                    }
+                )
+            )
+
+        self.assertEqual(result.error_code, 0)
+        mock_json_dump.assert_not_called()
+        self.assertIn("[DRY RUN] no changes applied.", result.output)
+        self.assertIn('-    "enabled": true', result.output)
+        self.assertIn('+    "enabled": false', result.output)
+
     @patch("json.dump")
     async def test_set_config_value(self, mock_json_dump):
         """Test setting a simple configuration value."""
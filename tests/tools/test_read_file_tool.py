@@ -0,0 +1,97 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import codecs
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.read_file_tool import SAMPLE_SIZE, ReadFileTool
+
+
+class TestReadFileTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = ReadFileTool()
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.file = Path(self.temp_dir.name) / "a.txt"
+
+    async def read(self, **arguments: object):
+        return await self.tool.execute(ToolCallArguments({"path": str(self.file), **arguments}))
+
+    async def test_detects_utf8(self):
+        self.file.write_bytes("café\r\nnaïve\n".encode("utf-8"))
+
+        result = await self.read()
+
+        self.assertIsNone(result.error)
+        self.assertIn("(encoding: utf-8)", result.output or "")
+        self.assertTrue((result.output or "").endswith("\ncafé\r\nnaïve\n"))
+
+    async def test_detects_utf16_with_and_without_byte_order_mark(self):
+        self.file.write_bytes(codecs.BOM_UTF16_BE + "héllo\n".encode("utf-16-be"))
+        result = await self.read()
+        self.assertIn("(encoding: utf-16-be)", result.output or "")
+        self.assertTrue((result.output or "").endswith("\nhéllo\n"))
+
+        self.file.write_bytes("hello\n".encode("utf-16-le"))
+        result = await self.read()
+        self.assertIn("(encoding: utf-16-le)", result.output or "")
+        self.assertTrue((result.output or "").endswith("\nhello\n"))
+
+    async def test_falls_back_to_latin1(self):
+        self.file.write_bytes("café\n".encode("latin-1"))
+
+        result = await self.read()
+
+        self.assertIn("(encoding: latin-1)", result.output or "")
+        self.assertTrue((result.output or "").endswith("\ncafé\n"))
+
+    async def test_non_utf8_after_the_sample_falls_back_to_latin1(self):
+        self.file.write_bytes(b"a" * SAMPLE_SIZE + "é".encode("latin-1"))
+
+        result = await self.read(range=[1, 1])
+
+        self.assertIn("(encoding: latin-1)", result.output or "")
+
+    async def test_explicit_encoding_that_does_not_match(self):
+        self.file.write_bytes("café\n".encode("latin-1"))
+
+        result = await self.read(encoding="utf-8")
+
+        self.assertIn("is not valid utf-8", result.error or "")
+
+    async def test_rejects_binary_files(self):
+        self.file.write_bytes(b"\x7fELF\x02\x01\x01\x00\x00\x00\x00\x00\x00\x00\x00\x00\x03\x00")
+
+        result = await self.read()
+
+        self.assertIn("binary", result.error or "")
+
+    async def test_range(self):
+        self.file.write_text("".join(f"line {i}\n" for i in range(1, 11)))
+
+        result = await self.read(range=[3, 4])
+        self.assertIn("lines 3 to 4", result.output or "")
+        self.assertTrue((result.output or "").endswith(":\nline 3\nline 4\n"))
+
+        result = await self.read(range=[9, -1])
+        self.assertTrue((result.output or "").endswith(":\nline 9\nline 10\n"))
+
+        result = await self.read(range=[20, -1])
+        self.assertIn("fewer than 20 lines", result.error or "")
+
+        result = await self.read(range=[4, 3])
+        self.assertIn("Invalid `range`", result.error or "")
+
+    async def test_requires_an_existing_absolute_path(self):
+        result = await self.tool.execute(ToolCallArguments({"path": "a.txt"}))
+        self.assertIn("not an absolute path", result.error or "")
+
+        result = await self.read()
+        self.assertIn("does not exist", result.error or "")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,199 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import unittest
+from unittest.mock import AsyncMock, MagicMock, patch
+
+from trae_agent.tools.base import ToolCall, ToolExecResult, ToolExecutor
+from trae_agent.tools.bash_tool import BashTool
+from trae_agent.tools.edit_tool import TextEditorTool
+from trae_agent.tools.run import truncate_middle
+from trae_agent.tools.task_done_tool import TaskDoneTool
+
+
+class TestToolExecutorConfirmation(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.handler = MagicMock(return_value=False)
+        self.executor = ToolExecutor(
+            [TextEditorTool(), TaskDoneTool()],
+            confirmation_handler=self.handler,
+            confirm_tools=["str_replace_based_edit_tool"],
+        )
+
+    async def test_declined_call_is_not_executed(self):
+        tool_call = ToolCall(
+            name="str_replace_based_edit_tool",
+            call_id="call_1",
+            arguments={"command": "create", "path": "/tmp/declined.txt", "file_text": "x"},
+        )
+        with patch("pathlib.Path.write_text") as mock_write:
+            result = await self.executor.execute_tool_call(tool_call)
+
+        self.handler.assert_called_once_with(tool_call)
+        mock_write.assert_not_called()
+        self.assertFalse(result.success)
+        self.assertIn("declined", result.error or "")
+
+    async def test_approved_call_is_executed(self):
+        self.handler.return_value = True
+        tool_call = ToolCall(
+            name="str_replace_based_edit_tool",
+            call_id="call_1",
+            arguments={"command": "create", "path": "/tmp/approved.txt", "file_text": "x"},
+        )
+        with (
+            patch("pathlib.Path.exists", return_value=False),
+            patch("pathlib.Path.write_text") as mock_write,
+        ):
+            result = await self.executor.execute_tool_call(tool_call)
+
+        mock_write.assert_called_once_with("x")
+        self.assertTrue(result.success)
+
+    async def test_read_only_and_unlisted_calls_skip_confirmation(self):
+        await self.executor.execute_tool_call(
+            ToolCall(
+                name="str_replace_based_edit_tool",
+                call_id="call_1",
+                arguments={"command": "view", "path": "/tmp"},
+            )
+        )
+        result = await self.executor.execute_tool_call(
+            ToolCall(name="task_done", call_id="call_2", arguments={})
+        )
+
+        self.handler.assert_not_called()
+        self.assertTrue(result.success)
+
+
+class TestToolExecutorObserver(unittest.IsolatedAsyncioTestCase):
+    async def test_observer_sees_every_call(self):
+        observer = MagicMock()
+        executor = ToolExecutor([TaskDoneTool()], observer=observer)
+        calls = [
+            ToolCall(name="task_done", call_id="call_1", arguments={}),
+            ToolCall(name="missing_tool", call_id="call_2", arguments={}),
+        ]
+
+        results = await executor.sequential_tool_call(calls)
+
+        self.assertEqual(
+            observer.call_args_list, [((call, result),) for call, result in zip(calls, results)]
+        )
+        self.assertFalse(results[1].success)
+
+
+class TestToolExecutorDryRun(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.bash = BashTool()
+        self.executor = ToolExecutor([TextEditorTool(), self.bash], dry_run=True)
+
+    async def asyncTearDown(self):
+        await self.bash.aclose()
+
+    async def test_mutating_calls_are_reported(self):
+        with (
+            patch("pathlib.Path.exists", return_value=False),
+            patch("pathlib.Path.write_text") as mock_write,
+        ):
+            result = await self.executor.execute_tool_call(
+                ToolCall(
+                    name="str_replace_based_edit_tool",
+                    call_id="call_1",
+                    arguments={"command": "create", "path": "/tmp/dry.txt", "file_text": "x"},
+                )
+            )
+
+        mock_write.assert_not_called()
+        self.assertTrue(result.success)
+        self.assertIn("[DRY RUN] no changes applied.", result.result or "")
+
+    async def test_read_only_commands_still_run(self):
+        result = await self.executor.execute_tool_call(
+            ToolCall(name="bash", call_id="call_1", arguments={"command": "echo hello | wc -c"})
+        )
+        self.assertEqual((result.result or "").strip(), "6")
+
+        result = await self.executor.execute_tool_call(
+            ToolCall(name="bash", call_id="call_2", arguments={"command": "echo hello > /tmp/x"})
+        )
+        self.assertIn("[DRY RUN] no changes applied.", result.result or "")
+
+
+class TestToolExecutorOutputLimit(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = TaskDoneTool()
+        self.output = "\n".join(f"line {i}" for i in range(100))
+        patcher = patch.object(
+            self.tool, "execute", new=AsyncMock(return_value=ToolExecResult(output=self.output))
+        )
+        patcher.start()
+        self.addCleanup(patcher.stop)
+
+    async def test_long_output_keeps_head_and_tail(self):
+        executor = ToolExecutor([self.tool], max_output=60)
+
+        result = await executor.execute_tool_call(
+            ToolCall(name="task_done", call_id="call_1", arguments={})
+        )
+
+        self.assertLessEqual(len(result.result or ""), 90)
+        self.assertTrue((result.result or "").startswith("line 0\n"))
+        self.assertTrue((result.result or "").endswith("line 99"))
+        self.assertIn("[... 93 lines omitted ...]", result.result or "")
+
+    async def test_max_output_argument_overrides_budget(self):
+        executor = ToolExecutor([self.tool], max_output=60)
+
+        result = await executor.execute_tool_call(
+            ToolCall(name="task_done", call_id="call_1", arguments={"max_output": 10000})
+        )
+
+        self.assertEqual(result.result, self.output)
+
+    async def test_output_and_error_share_the_budget(self):
+        executor = ToolExecutor([self.tool], max_output=60)
+        self.tool.execute.return_value = ToolExecResult(output=self.output, error="failed")
+
+        result = await executor.execute_tool_call(
+            ToolCall(name="task_done", call_id="call_1", arguments={})
+        )
+
+        self.assertEqual(result.error, "failed")
+        self.assertEqual(result.result, truncate_middle(self.output, 54))
+
+    def test_every_tool_accepts_max_output(self):
+        for tool in [self.tool, BashTool()]:
+            properties = tool.get_input_schema()["properties"]
+            assert isinstance(properties, dict)
+            self.assertEqual(properties["max_output"]["type"], "integer")
+            self.assertNotIn("max_output", [param.name for param in tool.parameters])
+
+    async def test_long_file_view_keeps_head_and_tail(self):
+        executor = ToolExecutor([TextEditorTool()], max_output=200)
+        content = "\n".join(f"line {i}" for i in range(5000))
+
+        with (
+            patch("pathlib.Path.exists", return_value=True),
+            patch("pathlib.Path.is_dir", return_value=False),
+            patch("pathlib.Path.read_text", return_value=content),
+        ):
+            result = await executor.execute_tool_call(
+                ToolCall(
+                    name="str_replace_based_edit_tool",
+                    call_id="call_1",
+                    arguments={"command": "view", "path": "/tmp/long_file.txt"},
+                )
+            )
+
+        self.assertIn("lines omitted ...]", result.result or "")
+        self.assertTrue((result.result or "").rstrip().endswith("line 4999"))
+
+    def test_cut_inside_a_line_reports_characters(self):
+        truncated = truncate_middle("a" * 50 + "\n" + "b" * 50, 20)
+
+        self.assertEqual(truncated, "a" * 10 + "[... 81 characters omitted ...]" + "b" * 10)
+
+
+if __name__ == "__main__":
+    unittest.main()
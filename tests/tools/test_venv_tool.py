@@ -0,0 +1,125 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import shutil
+import sys
+import tempfile
+import unittest
+from pathlib import Path
+from unittest.mock import patch
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.venv_tool import ActivateVenvTool, CreateVenvTool, ListVenvsTool
+
+
+class TestVenvTools(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.root = Path(self.temp_dir.name)
+
+    def make_python_venv(self, path: Path, version: str = "3.12.1"):
+        (path / "bin").mkdir(parents=True)
+        (path / "pyvenv.cfg").write_text(f"home = /usr/bin\nversion = {version}\n")
+
+    def make_node_env(self, path: Path):
+        (path / "bin").mkdir(parents=True)
+        (path / "bin" / "node").write_text("")
+        (path / "bin" / "activate").write_text("NODE_VIRTUAL_ENV=...\n")
+
+    async def test_create_python_venv(self):
+        interpreter = Path(sys.executable).name
+        venv = self.root / ".venv"
+
+        result = await CreateVenvTool().execute(
+            ToolCallArguments({"path": str(venv), "interpreter": interpreter})
+        )
+
+        self.assertIsNone(result.error)
+        self.assertTrue((venv / "pyvenv.cfg").is_file())
+        self.assertTrue((venv / "bin" / "python").exists())
+
+    async def test_create_dry_run(self):
+        venv = self.root / ".venv"
+
+        result = await CreateVenvTool().dry_run(
+            ToolCallArguments({"path": str(venv), "interpreter": Path(sys.executable).name})
+        )
+
+        self.assertIn("[DRY RUN] no changes applied.", result.output or "")
+        self.assertIn(f"-m venv {venv}", result.output or "")
+        self.assertFalse(venv.exists())
+
+    async def test_create_rejects_bad_arguments(self):
+        tool = CreateVenvTool()
+        (self.root / "taken").mkdir()
+        (self.root / "taken" / "file").write_text("")
+
+        result = await tool.execute(
+            ToolCallArguments({"path": str(self.root / "taken"), "interpreter": "python3"})
+        )
+        self.assertIn("already exists", result.error or "")
+
+        result = await tool.execute(
+            ToolCallArguments({"path": str(self.root / "env"), "interpreter": "ruby3"})
+        )
+        self.assertIn("Unsupported interpreter: ruby3", result.error or "")
+
+        with patch("shutil.which", return_value=None):
+            result = await tool.execute(
+                ToolCallArguments({"path": str(self.root / "env"), "interpreter": "node18"})
+            )
+        self.assertIn("needs `nodeenv`", result.error or "")
+
+    @unittest.skipIf(shutil.which("nodeenv") is None, "nodeenv is not installed")
+    async def test_create_node_env_rejects_other_installed_version(self):
+        result = await CreateVenvTool().execute(
+            ToolCallArguments({"path": str(self.root / "env"), "interpreter": "node1"})
+        )
+        self.assertIn("not version 1", result.error or "")
+
+    async def test_activate(self):
+        venv = self.root / "my env"
+        self.make_python_venv(venv)
+
+        result = await ActivateVenvTool().execute(ToolCallArguments({"path": str(venv)}))
+
+        self.assertEqual(
+            result.output,
+            f"Run these commands to activate the environment at {venv}:\n"
+            f"export VIRTUAL_ENV='{venv}'\n"
+            f"export PATH='{venv}/bin':\"$PATH\"\n"
+            "unset PYTHONHOME",
+        )
+
+        node_env = self.root / "node_env"
+        self.make_node_env(node_env)
+        result = await ActivateVenvTool().execute(ToolCallArguments({"path": str(node_env)}))
+        self.assertIn(f"export NODE_VIRTUAL_ENV={node_env}\n", result.output or "")
+
+        result = await ActivateVenvTool().execute(ToolCallArguments({"path": str(self.root)}))
+        self.assertIn("No Python venv or Node environment found", result.error or "")
+
+    async def test_list_venvs(self):
+        self.make_python_venv(self.root / ".venv")
+        self.make_node_env(self.root / "web" / "node_env")
+        self.make_python_venv(self.root / "node_modules" / "pkg" / "venv")
+        self.make_python_venv(self.root / "a" / "b" / "c" / "too_deep")
+
+        result = await ListVenvsTool().execute(ToolCallArguments({"path": str(self.root)}))
+
+        self.assertEqual(
+            result.output,
+            f"Environments in {self.root}:\n"
+            f"{self.root}/.venv (Python 3.12.1)\n"
+            f"{self.root}/web/node_env (Node)",
+        )
+
+        empty = self.root / "empty"
+        empty.mkdir()
+        result = await ListVenvsTool().execute(ToolCallArguments({"path": str(empty)}))
+        self.assertEqual(result.output, f"No environments found in {empty}.")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,102 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import asyncio
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.watch_path_tool import WatchPathTool
+
+
+class TestWatchPathTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = WatchPathTool()
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.root = Path(self.temp_dir.name)
+        (self.root / "old.txt").write_text("old")
+
+    async def watch_while(self, change, **arguments: object):
+        """Start watching, apply `change` once the first snapshot is taken, and return the result."""
+        watch = asyncio.create_task(
+            self.tool.execute(
+                ToolCallArguments({"path": str(self.root), "timeout": 5, **arguments})
+            )
+        )
+        await asyncio.sleep(0.3)
+        change()
+        return await watch
+
+    async def test_reports_created_and_deleted_files(self):
+        def change():
+            (self.root / "sub").mkdir()
+            (self.root / "sub" / "new.txt").write_text("new")
+            (self.root / "old.txt").unlink()
+
+        result = await self.watch_while(change)
+
+        self.assertEqual(
+            result.output, f"Changes to {self.root}:\ndeleted old.txt\ncreated sub/new.txt"
+        )
+
+    async def test_debounce_coalesces_a_burst_of_writes(self):
+        async def burst():
+            for i in range(3):
+                (self.root / "old.txt").write_text(f"v{i}")
+                await asyncio.sleep(0.15)
+
+        watch = asyncio.create_task(
+            self.tool.execute(
+                ToolCallArguments({"path": str(self.root), "timeout": 5, "debounce_ms": 500})
+            )
+        )
+        await asyncio.sleep(0.3)
+        await burst()
+        result = await watch
+
+        self.assertEqual(result.output, f"Changes to {self.root}:\nmodified old.txt")
+
+    async def test_patterns_filter_files(self):
+        def change():
+            (self.root / "a.txt").write_text("a")
+            (self.root / "b.py").write_text("b")
+
+        result = await self.watch_while(change, patterns=["*.py"])
+
+        self.assertIn("created b.py", result.output or "")
+        self.assertNotIn("a.txt", result.output or "")
+
+    async def test_watch_a_file_until_it_is_created(self):
+        target = self.root / "build" / "out.bin"
+        target.parent.mkdir()
+        watch = asyncio.create_task(
+            self.tool.execute(ToolCallArguments({"path": str(target), "timeout": 5}))
+        )
+        await asyncio.sleep(0.3)
+        target.write_bytes(b"done")
+
+        result = await watch
+
+        self.assertIn("created out.bin", result.output or "")
+
+    async def test_timeout_without_changes(self):
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.root), "timeout": 0.3})
+        )
+
+        self.assertEqual(result.output, f"No changes to {self.root} within 0.3 seconds.")
+
+    async def test_invalid_arguments(self):
+        result = await self.tool.execute(ToolCallArguments({"path": "relative"}))
+        self.assertIn("not an absolute path", result.error or "")
+
+        result = await self.tool.execute(
+            ToolCallArguments({"path": str(self.root), "timeout": 3600})
+        )
+        self.assertIn("`timeout`", result.error or "")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,84 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import DirectoryStack, ToolCall, ToolCallArguments, ToolExecutor
+from trae_agent.tools.read_file_tool import ReadFileTool
+from trae_agent.tools.working_directory_tool import WorkingDirectoryTool
+
+
+class TestWorkingDirectoryTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.root = Path(self.temp_dir.name)
+        (self.root / "src" / "pkg").mkdir(parents=True)
+        (self.root / "src" / "pkg" / "mod.py").write_text("x = 1\n")
+        self.tool = WorkingDirectoryTool()
+        self.tool.directory_stack = DirectoryStack(self.root)
+
+    async def run_command(self, command: str, path: str | None = None):
+        arguments: dict[str, str] = {"command": command}
+        if path is not None:
+            arguments["path"] = path
+        return await self.tool.execute(ToolCallArguments(arguments))
+
+    async def test_pushd_and_popd(self):
+        result = await self.run_command("pushd", "src/pkg")
+        self.assertEqual(
+            result.output,
+            f"Current directory: {self.root}/src/pkg\n"
+            f"Pushed directories, most recent first:\n{self.root}",
+        )
+
+        result = await self.run_command("popd")
+        self.assertEqual(result.output, f"Current directory: {self.root}")
+
+        result = await self.run_command("popd")
+        self.assertIn("The directory stack is empty", result.error or "")
+
+    async def test_cd_replaces_the_current_directory(self):
+        await self.run_command("pushd", "src")
+        await self.run_command("cd", "pkg")
+        result = await self.run_command("cd", "..")
+
+        self.assertEqual(
+            result.output,
+            f"Current directory: {self.root}/src\nPushed directories, most recent first:\n{self.root}",
+        )
+
+    async def test_target_must_be_an_existing_directory(self):
+        result = await self.run_command("cd", "missing")
+        self.assertIn("does not exist", result.error or "")
+
+        result = await self.run_command("pushd", "src/pkg/mod.py")
+        self.assertIn("is not a directory", result.error or "")
+
+        result = await self.run_command("pwd")
+        self.assertEqual(result.output, f"Current directory: {self.root}")
+
+    async def test_other_tools_resolve_relative_paths_against_the_stack(self):
+        executor = ToolExecutor(
+            [WorkingDirectoryTool(), ReadFileTool()], directory_stack=DirectoryStack(self.root)
+        )
+
+        await executor.execute_tool_call(
+            ToolCall(
+                name="working_directory",
+                call_id="call_1",
+                arguments={"command": "cd", "path": "src/pkg"},
+            )
+        )
+        result = await executor.execute_tool_call(
+            ToolCall(name="read_file", call_id="call_2", arguments={"path": "mod.py"})
+        )
+
+        self.assertTrue(result.success)
+        self.assertIn("x = 1", result.result or "")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,94 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import codecs
+import os
+import tempfile
+import unittest
+from pathlib import Path
+from unittest.mock import patch
+
+from trae_agent.tools.base import ToolCallArguments
+from trae_agent.tools.write_file_tool import WriteFileTool
+
+
+class TestWriteFileTool(unittest.IsolatedAsyncioTestCase):
+    def setUp(self):
+        self.tool = WriteFileTool()
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.root = Path(self.temp_dir.name)
+        self.file = self.root / "a.txt"
+
+    async def write(self, **arguments: object):
+        return await self.tool.execute(ToolCallArguments({"path": str(self.file), **arguments}))
+
+    async def test_writes_utf8_by_default(self):
+        result = await self.write(content="café\n")
+
+        self.assertIsNone(result.error)
+        self.assertEqual(self.file.read_bytes(), "café\n".encode("utf-8"))
+
+    async def test_encodings(self):
+        await self.write(content="café", encoding="latin-1")
+        self.assertEqual(self.file.read_bytes(), b"caf\xe9")
+
+        await self.write(content="hi", encoding="utf-16-be")
+        self.assertEqual(self.file.read_bytes(), codecs.BOM_UTF16_BE + b"\x00h\x00i")
+
+        await self.write(content="hi", encoding="utf-16-le")
+        self.assertEqual(self.file.read_bytes(), codecs.BOM_UTF16_LE + b"h\x00i\x00")
+
+    async def test_unencodable_content(self):
+        self.file.write_text("old")
+
+        result = await self.write(content="€", encoding="latin-1")
+
+        self.assertIn("cannot be written as latin-1", result.error or "")
+        self.assertEqual(self.file.read_text(), "old")
+
+    async def test_create_dirs(self):
+        self.file = self.root / "sub" / "dir" / "a.txt"
+
+        result = await self.write(content="x")
+        self.assertIn("Set `create_dirs` to true", result.error or "")
+
+        result = await self.write(content="x", create_dirs=True)
+        self.assertIsNone(result.error)
+        self.assertEqual(self.file.read_text(), "x")
+
+    async def test_atomic_write_keeps_permissions(self):
+        self.file.write_text("old")
+        os.chmod(self.file, 0o600)
+
+        result = await self.write(content="new", atomic=True)
+
+        self.assertIsNone(result.error)
+        self.assertEqual(self.file.read_text(), "new")
+        self.assertEqual(self.file.stat().st_mode & 0o777, 0o600)
+        self.assertEqual(os.listdir(self.root), ["a.txt"])
+
+    async def test_failed_atomic_write_leaves_the_file_alone(self):
+        self.file.write_text("old")
+
+        with patch("os.replace", side_effect=OSError("disk full")):
+            result = await self.write(content="new", atomic=True)
+
+        self.assertIn("disk full", result.error or "")
+        self.assertEqual(self.file.read_text(), "old")
+        self.assertEqual(os.listdir(self.root), ["a.txt"])
+
+    async def test_dry_run(self):
+        self.file.write_text("old\n")
+
+        result = await self.tool.dry_run(
+            ToolCallArguments({"path": str(self.file), "content": "new\n"})
+        )
+
+        self.assertEqual(self.file.read_text(), "old\n")
+        self.assertIn("[DRY RUN] no changes applied.", result.output or "")
+        self.assertIn("-old\n+new", result.output or "")
+
+
+if __name__ == "__main__":
+    unittest.main()
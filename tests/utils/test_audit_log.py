@@ -0,0 +1,75 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import json
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import ToolCall, ToolResult
+from trae_agent.utils.audit_log import REDACTED, AuditLog, redact
+
+
+class TestAuditLog(unittest.TestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.audit_log = AuditLog(Path(self.temp_dir.name) / "logs" / "audit.jsonl")
+
+    def test_redact_sensitive_keys_and_assignments(self):
+        redacted = redact(
+            {
+                "command": "API_KEY=abc123 ./deploy.sh --password=hunter2 --user=admin",
+                "env": ["GITHUB_TOKEN=ghp_x", "DEBUG=1"],
+                "headers": {"Authorization": "Bearer x", "Accept": "text/plain"},
+                "password": "hunter2",
+            }
+        )
+
+        self.assertEqual(
+            redacted,
+            {
+                "command": f"API_KEY={REDACTED} ./deploy.sh --password={REDACTED} --user=admin",
+                "env": [f"GITHUB_TOKEN={REDACTED}", "DEBUG=1"],
+                "headers": {"Authorization": REDACTED, "Accept": "text/plain"},
+                "password": REDACTED,
+            },
+        )
+
+    def test_redact_keeps_ordinary_token_names(self):
+        arguments = {"page_token": "200", "max_tokens": 5, "access_token": "abc"}
+
+        self.assertEqual(
+            redact(arguments), {"page_token": "200", "max_tokens": 5, "access_token": REDACTED}
+        )
+
+    def test_redact_headers_and_flags(self):
+        command = (
+            "curl -H 'Authorization: Bearer abc123' -H 'Accept: text/plain' --token abc "
+            '--password "hunter 2" --retry 3 https://example.com'
+        )
+
+        self.assertEqual(
+            redact(command),
+            f"curl -H 'Authorization: Bearer {REDACTED}' -H 'Accept: text/plain' "
+            f"--token {REDACTED} --password {REDACTED} --retry 3 https://example.com",
+        )
+
+    def test_record_appends_across_instances(self):
+        for session_id, success in [("first", True), ("second", False)]:
+            AuditLog(self.audit_log.path).record(
+                ToolCall(name="bash", call_id="call_1", arguments={"command": "ls", "token": "x"}),
+                ToolResult(call_id="call_1", name="bash", success=success),
+                session_id=session_id,
+            )
+
+        entries = [json.loads(line) for line in self.audit_log.path.read_text().splitlines()]
+        self.assertEqual([entry["session_id"] for entry in entries], ["first", "second"])
+        self.assertEqual([entry["success"] for entry in entries], [True, False])
+        self.assertEqual(entries[0]["tool"], "bash")
+        self.assertEqual(entries[0]["arguments"], {"command": "ls", "token": REDACTED})
+        self.assertIn("timestamp", entries[0])
+
+
+if __name__ == "__main__":
+    unittest.main()
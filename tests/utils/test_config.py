@@ -1,7 +1,9 @@
 # Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
 # SPDX-License-Identifier: MIT
 
+import os
 import unittest
+from pathlib import Path
 from unittest.mock import patch
 
 from trae_agent.utils.anthropic_client import AnthropicClient
@@ -241,5 +243,32 @@ class TestLakeviewConfig(unittest.TestCase):
         self.assertIsNone(config.lakeview_config)
 
 
+class TestConfirmToolsConfig(unittest.TestCase):
+    def test_confirm_tools_defaults_to_empty(self):
+        self.assertEqual(Config({}).confirm_tools, [])
+
+    def test_confirm_tools_from_config(self):
+        config = Config({"confirm_tools": ["bash", "str_replace_based_edit_tool"]})
+        self.assertEqual(config.confirm_tools, ["bash", "str_replace_based_edit_tool"])
+
+
+class TestAuditLogConfig(unittest.TestCase):
+    def test_audit_log_defaults_to_the_data_dir(self):
+        self.assertEqual(Config({}).audit_log, str(Path.home() / ".trae-agent" / "audit.jsonl"))
+
+    def test_empty_audit_log_disables_it(self):
+        self.assertEqual(Config({"audit_log": None}).audit_log, "")
+
+
+
+class TestDryRunConfig(unittest.TestCase):
+    def test_dry_run_from_config(self):
+        self.assertFalse(Config({}).dry_run)
+        self.assertTrue(Config({"dry_run": True}).dry_run)
+
+    @patch.dict(os.environ, {"TRAE_DRY_RUN": "true"})
+    def test_environment_variable_enables_dry_run(self):
+        self.assertTrue(Config({}).dry_run)
+
 if __name__ == "__main__":
     unittest.main()
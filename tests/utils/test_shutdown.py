@@ -0,0 +1,110 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import signal
+import threading
+import unittest
+from unittest.mock import patch
+
+from trae_agent.utils.shutdown import FORCED_EXIT_CODE, ShutdownCoordinator
+
+
+class TestShutdownCoordinator(unittest.TestCase):
+    def test_hooks_run_in_registration_order(self):
+        calls: list[str] = []
+
+        async def close_session():
+            calls.append("session")
+
+        shutdown = ShutdownCoordinator()
+        shutdown.register("tools", lambda: calls.append("tools"))
+        shutdown.register("session", close_session)
+        shutdown.register("trajectory", lambda: calls.append("trajectory"))
+        shutdown.shutdown()
+
+        self.assertEqual(calls, ["tools", "session", "trajectory"])
+
+    def test_failing_hook_does_not_stop_the_rest(self):
+        calls: list[str] = []
+
+        def fail():
+            raise RuntimeError("boom")
+
+        shutdown = ShutdownCoordinator()
+        shutdown.register("broken", fail)
+        shutdown.register("trajectory", lambda: calls.append("trajectory"))
+        with patch("sys.stderr") as stderr:
+            shutdown.shutdown()
+
+        self.assertEqual(calls, ["trajectory"])
+        written = "".join(call.args[0] for call in stderr.write.call_args_list)
+        self.assertIn("Shutdown hook 'broken' failed: boom", written)
+
+    def test_force_exit_after_deadline(self):
+        released = threading.Event()
+        exited = threading.Event()
+
+        def exit_(code: int):
+            self.assertEqual(code, FORCED_EXIT_CODE)
+            exited.set()
+            released.set()
+
+        shutdown = ShutdownCoordinator(deadline=0.1)
+        shutdown.register("stuck", lambda: released.wait(5))
+        with patch("os._exit", side_effect=exit_), patch("sys.stderr"):
+            shutdown.shutdown()
+
+        self.assertTrue(exited.is_set())
+
+    def test_second_signal_exits_immediately(self):
+        shutdown = ShutdownCoordinator()
+
+        with self.assertRaises(KeyboardInterrupt):
+            shutdown._handle_signal(signal.SIGTERM, None)
+        with patch("os._exit", side_effect=SystemExit) as exit_, self.assertRaises(SystemExit):
+            shutdown._handle_signal(signal.SIGTERM, None)
+
+        exit_.assert_called_once_with(128 + signal.SIGTERM)
+
+    def test_later_signal_does_not_interrupt_cleanup(self):
+        shutdown = ShutdownCoordinator()
+
+        with patch("time.monotonic", return_value=100.0), self.assertRaises(KeyboardInterrupt):
+            shutdown._handle_signal(signal.SIGINT, None)
+        with patch("time.monotonic", return_value=110.0), patch("os._exit") as exit_:
+            shutdown._handle_signal(signal.SIGINT, None)
+
+        exit_.assert_not_called()
+
+    def test_reset_forgets_hooks_and_signals(self):
+        calls: list[str] = []
+        shutdown = ShutdownCoordinator()
+        shutdown.register("trajectory", lambda: calls.append("trajectory"))
+        with patch("time.monotonic", return_value=100.0), self.assertRaises(KeyboardInterrupt):
+            shutdown._handle_signal(signal.SIGTERM, None)
+        self.assertEqual(shutdown.received_signal, signal.SIGTERM)
+
+        shutdown.reset()
+        shutdown.shutdown()
+        self.assertEqual(calls, [])
+        self.assertIsNone(shutdown.received_signal)
+        with patch("time.monotonic", return_value=101.0), self.assertRaises(KeyboardInterrupt):
+            shutdown._handle_signal(signal.SIGINT, None)
+
+    def test_uninstall_restores_previous_handlers(self):
+        def previous(signum, frame):
+            pass
+
+        original = signal.signal(signal.SIGTERM, previous)
+        self.addCleanup(signal.signal, signal.SIGTERM, original)
+        shutdown = ShutdownCoordinator()
+
+        shutdown.install()
+        self.assertNotEqual(signal.getsignal(signal.SIGTERM), previous)
+        shutdown.uninstall()
+
+        self.assertEqual(signal.getsignal(signal.SIGTERM), previous)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,56 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import os
+import tempfile
+import unittest
+from pathlib import Path
+from unittest.mock import patch
+
+from trae_agent.utils.trajectory_index import INDEX_FILE_NAME, TrajectoryIndex
+from trae_agent.utils.trajectory_recorder import TrajectoryRecorder
+
+
+class TestTrajectoryIndex(unittest.TestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.directory = Path(self.temp_dir.name)
+        for name, task in [("first", "Fix the login bug"), ("second", "Add a README")]:
+            self.record(name, task)
+
+    def record(self, name: str, task: str) -> Path:
+        recorder = TrajectoryRecorder(str(self.directory / f"{name}.json"))
+        recorder.start_recording(task=task, provider="anthropic", model="test-model", max_steps=5)
+        return recorder.trajectory_path
+
+    def test_candidates_match_partial_terms(self):
+        index = TrajectoryIndex(self.directory)
+        index.refresh()
+
+        self.assertEqual(index.candidates("GIN BU"), [self.directory / "first.json"])
+        self.assertEqual(index.candidates("readme"), [self.directory / "second.json"])
+        self.assertEqual(index.candidates("migration"), [])
+        self.assertEqual(len(index.candidates("...")), 2)
+
+    def test_cached_index_only_reads_changed_files(self):
+        TrajectoryIndex(self.directory).refresh()
+        self.assertTrue((self.directory / INDEX_FILE_NAME).exists())
+
+        changed = self.record("second", "Add a changelog")
+        stat = changed.stat()
+        os.utime(changed, ns=(stat.st_atime_ns, stat.st_mtime_ns + 1_000_000_000))
+        (self.directory / "first.json").unlink()
+
+        with patch.object(TrajectoryRecorder, "load", wraps=TrajectoryRecorder.load) as mock_load:
+            index = TrajectoryIndex(self.directory)
+            index.refresh()
+
+        mock_load.assert_called_once_with(str(changed))
+        self.assertEqual(index.candidates("changelog"), [changed])
+        self.assertEqual(index.candidates("readme"), [])
+        self.assertEqual(index.candidates("login"), [])
+
+
+if __name__ == "__main__":
+    unittest.main()
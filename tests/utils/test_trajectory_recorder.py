@@ -0,0 +1,82 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+import json
+import tempfile
+import unittest
+from pathlib import Path
+
+from trae_agent.tools.base import ToolCall, ToolResult
+from trae_agent.utils.trajectory_recorder import TrajectoryRecorder
+
+
+class TestTrajectoryRecorder(unittest.TestCase):
+    def setUp(self):
+        self.temp_dir = tempfile.TemporaryDirectory()
+        self.addCleanup(self.temp_dir.cleanup)
+        self.trajectory_path = Path(self.temp_dir.name) / "trajectory.json"
+        self.recorder = TrajectoryRecorder(str(self.trajectory_path))
+        self.recorder.start_recording(
+            task="test task", provider="anthropic", model="test-model", max_steps=5
+        )
+        self.recorder.record_agent_step(
+            step_number=1,
+            state="calling_tool",
+            tool_calls=[
+                ToolCall(name="bash", call_id="call_1", arguments={"command": "echo hi"}),
+                ToolCall(name="bash", call_id="call_2", arguments={"command": "false"}),
+            ],
+            tool_results=[
+                ToolResult(call_id="call_2", name="bash", success=False, error="exit 1"),
+                ToolResult(call_id="call_1", name="bash", success=True, result="hi"),
+            ],
+        )
+
+    def test_get_tool_call_records_pairs_results_by_call_id(self):
+        records = self.recorder.get_tool_call_records()
+
+        self.assertEqual(len(records), 2)
+        self.assertEqual(records[0]["arguments"], {"command": "echo hi"})
+        self.assertEqual(records[0]["result"], "hi")
+        self.assertTrue(records[0]["success"])
+        self.assertEqual(records[1]["error"], "exit 1")
+        self.assertFalse(records[1]["success"])
+
+    def test_export_tool_calls_writes_jsonl(self):
+        export_path = Path(self.temp_dir.name) / "exports" / "tool_calls.jsonl"
+
+        count = self.recorder.export_tool_calls(export_path)
+
+        lines = export_path.read_text().splitlines()
+        self.assertEqual(count, 2)
+        self.assertEqual([json.loads(line)["name"] for line in lines], ["bash", "bash"])
+
+    def test_load_round_trips_saved_trajectory(self):
+        loaded = TrajectoryRecorder.load(str(self.trajectory_path))
+
+        self.assertEqual(loaded.trajectory_data["task"], "test task")
+        self.assertEqual(loaded.get_tool_call_records(), self.recorder.get_tool_call_records())
+
+    def test_export_markdown(self):
+        self.recorder.finalize_recording(success=True, final_result="All done")
+        export_path = Path(self.temp_dir.name) / "trajectory.md"
+
+        self.recorder.export_markdown(export_path)
+
+        markdown = export_path.read_text()
+        self.assertTrue(markdown.startswith("---\ntrajectory_id: \"trajectory\"\n"))
+        self.assertIn('provider: "anthropic"', markdown)
+        self.assertIn("## User\n\ntest task", markdown)
+        self.assertIn('## Assistant\n\n```bash\n{\n  "command": "echo hi"\n}\n```', markdown)
+        self.assertIn("**Error:**\n\n```\nexit 1\n```", markdown)
+        self.assertIn("## Result\n\nSuccess: yes\n\nAll done", markdown)
+
+    def test_markdown_fence_is_longer_than_nested_fences(self):
+        fenced = TrajectoryRecorder._markdown_fence("```python\nprint()\n```")
+
+        self.assertTrue(fenced.startswith("````\n"))
+        self.assertTrue(fenced.endswith("\n````"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -3,14 +3,25 @@
 
 """Base Agent class for LLM-based agents."""
 
+import functools
 from abc import ABC, abstractmethod
 
-from ..tools.base import Tool, ToolCall, ToolExecutor, ToolResult
+from ..tools.base import (
+    ConfirmationHandler,
+    Tool,
+    ToolCall,
+    ToolCallObserver,
+    ToolExecutor,
+    ToolResult,
+)
 from ..tools.ckg.ckg_database import clear_older_ckg
+from ..tools.run import MAX_RESPONSE_LEN
+from ..utils.audit_log import AuditLog
 from ..utils.cli_console import CLIConsole
 from ..utils.config import Config, ModelParameters
 from ..utils.llm_basics import LLMMessage, LLMResponse
 from ..utils.llm_client import LLMClient
+from ..utils.shutdown import ShutdownCoordinator
 from ..utils.trajectory_recorder import TrajectoryRecorder
 from .agent_basics import AgentExecution, AgentState, AgentStep
 
@@ -48,6 +59,22 @@ class Agent(ABC):
         self._tool_caller: ToolExecutor = ToolExecutor([])
         self._cli_console: CLIConsole | None = None
 
+        # Tools whose non read-only calls must be approved by the confirmation handler
+        self._confirm_tools: list[str] = config.confirm_tools if config is not None else []
+        self._confirmation_handler: ConfirmationHandler | None = None
+        # Character budget for each tool result, see ToolExecutor
+        self._max_tool_output: int = (
+            config.max_tool_output if config is not None else MAX_RESPONSE_LEN
+        )
+
+        # Report file edits and shell commands instead of applying them, see ToolExecutor
+        self._dry_run: bool = config.dry_run if config is not None else False
+
+        # Append-only log of every tool call across runs, disabled by an empty audit_log path
+        self._audit_log: AuditLog | None = (
+            AuditLog(config.audit_log) if config is not None and config.audit_log else None
+        )
+
         # Trajectory recorder
         self._trajectory_recorder: TrajectoryRecorder | None = None
 
@@ -98,6 +125,28 @@ class Agent(ABC):
         """Get the tools available to this agent."""
         return self._tools
 
+    def set_confirmation_handler(self, handler: ConfirmationHandler | None) -> None:
+        """Set the hook that approves or declines calls to the tools listed in confirm_tools."""
+        self._confirmation_handler = handler
+
+    def _tool_call_observer(self) -> ToolCallObserver | None:
+        """Bind the audit log to the current trajectory file, which identifies the run."""
+        if self._audit_log is None:
+            return None
+        recorder = self._trajectory_recorder
+        session_id = recorder.trajectory_path.stem if recorder is not None else None
+        return functools.partial(self._audit_log.record, session_id=session_id)
+
+    async def aclose_tools(self) -> None:
+        """Release resources held by the agent's tools, e.g. spawned shell processes."""
+        for tool in self._tools:
+            await tool.aclose()
+
+    def register_shutdown_hooks(self, shutdown: ShutdownCoordinator) -> None:
+        """Have each tool release its resources, e.g. spawned shell processes, at shutdown."""
+        for tool in self._tools:
+            shutdown.register(f"close {tool.name}", tool.aclose)
+
     @property
     def task(self) -> str:
         """Get the current task of the agent."""
@@ -208,6 +257,9 @@ class Agent(ABC):
 
         except Exception as e:
             execution.final_result = f"Agent execution failed: {str(e)}"
+        finally:
+            # Tools are created per task, and their processes must not outlive it or the loop
+            await self.aclose_tools()
 
         execution.execution_time = time.time() - start_time
 
@@ -23,6 +23,16 @@ TraeAgentToolNames = [
     "json_edit_tool",
     "task_done",
     "bash",
+    "read_file",
+    "write_file",
+    "watch_path",
+    "file_hash",
+    "dependency_graph",
+    "working_directory",
+    "create_venv",
+    "activate_venv",
+    "list_venvs",
+    "format_code",
 ]
 
 
@@ -94,7 +104,14 @@ class TraeAgent(Agent):
         self._tools: list[Tool] = [
             tools_registry[tool_name](model_provider=provider) for tool_name in tool_names
         ]
-        self._tool_caller: ToolExecutor = ToolExecutor(self._tools)
+        self._tool_caller: ToolExecutor = ToolExecutor(
+            self._tools,
+            confirmation_handler=self._confirmation_handler,
+            confirm_tools=self._confirm_tools,
+            max_output=self._max_tool_output,
+            observer=self._tool_call_observer(),
+            dry_run=self._dry_run,
+        )
 
         self._initial_messages: list[LLMMessage] = []
         self._initial_messages.append(LLMMessage(role="system", content=self.get_system_prompt()))
@@ -4,21 +4,34 @@
 """Command Line Interface for Trae Agent."""
 
 import asyncio
+import difflib
+import json
 import os
+import re
+import signal
 import sys
 import traceback
+from datetime import datetime, timedelta
 from pathlib import Path
+from typing import NoReturn, TextIO
 
 import click
 from dotenv import load_dotenv
 from rich.console import Console
+from rich.markup import escape
 from rich.panel import Panel
 from rich.table import Table
 
 from trae_agent.utils.cli_console import CLIConsole
 
 from .agent import TraeAgent
+from .agent.agent_basics import AgentExecution
+from .tools.base import DRY_RUN_ENV_VAR, ToolCall, ToolExecutor, ToolResult
+from .utils.audit_log import AuditLog
 from .utils.config import Config, load_config
+from .utils.shutdown import ShutdownCoordinator
+from .utils.trajectory_index import NOT_A_TRAJECTORY_ERRORS, TrajectoryIndex
+from .utils.trajectory_recorder import TrajectoryRecorder
 
 # Load environment variables
 _ = load_dotenv()
@@ -26,11 +39,28 @@ _ = load_dotenv()
 console = Console()
 
 
-def create_agent(config: Config) -> TraeAgent:
+def exit_with_error(
+    error: str, result_stdout: TextIO | None = None, trajectory_path: str | None = None
+) -> NoReturn:
+    """
+    exit_with_error reports an error that stops the command and exits with code 1.
+    Args:
+        error: the message to show.
+        result_stdout: where headless runs print their JSON result, None when not headless.
+        trajectory_path: the trajectory file of the run, if it got that far.
+    """
+    console.print(f"[red]{error}[/red]")
+    if result_stdout is not None:
+        print(json.dumps(execution_summary(None, trajectory_path, error)), file=result_stdout)
+    sys.exit(1)
+
+
+def create_agent(config: Config, result_stdout: TextIO | None = None) -> TraeAgent:
     """
     create_agent creates a Trae Agent with the specified configuration.
     Args:
         config: Agent configuration. It is expected that the config comes from load_config.
+        result_stdout: where a headless run prints its JSON result if the agent cannot be created.
     Return:
         TraeAgent object
     """
@@ -40,14 +70,84 @@ def create_agent(config: Config) -> TraeAgent:
         return agent
 
     except Exception as e:
-        console.print(f"[red]Error creating agent: {e}[/red]")
         console.print(traceback.format_exc())
-        sys.exit(1)
+        exit_with_error(f"Error creating agent: {e}", result_stdout)
+
+
+def save_interrupted_trajectory(agent: TraeAgent) -> None:
+    """Finalize the trajectory of a task that was interrupted before it finished."""
+    if agent.trajectory_recorder:
+        agent.trajectory_recorder.finalize_recording(
+            success=False, final_result="Task execution interrupted by user"
+        )
+
+
+def interrupted_message(trajectory_path: str) -> str:
+    """interrupted_message tells the user which session an interrupted task was saved as."""
+    return f"interrupted, session saved as {Path(trajectory_path).stem}"
 
 
 # Display functions moved to agent/base.py for real-time progress display
 
 
+def enable_dry_run(config: Config, dry_run: bool) -> None:
+    """
+    enable_dry_run turns on dry-run mode in the config when the --dry-run flag is given.
+    The dry_run config key and the TRAE_DRY_RUN environment variable are honored even without it.
+    """
+    if dry_run:
+        config.dry_run = True
+    if config.dry_run:
+        console.print(
+            "[yellow]Dry run: file edits and shell commands will be reported but not applied[/yellow]"
+        )
+
+
+# When set to a truthy value, calls to the tools in confirm_tools run without asking
+AUTO_APPROVE_ENV_VAR: str = "TRAE_AUTO_APPROVE"
+
+
+def should_auto_approve(auto_approve: bool) -> bool:
+    """should_auto_approve resolves the --yes flag, falling back to TRAE_AUTO_APPROVE."""
+    return auto_approve or os.getenv(AUTO_APPROVE_ENV_VAR, "").lower() in ("1", "true", "yes")
+
+
+def decline_tool_call(tool_call: ToolCall) -> bool:  # pyright: ignore[reportUnusedParameter]
+    """decline_tool_call is the confirmation handler of headless runs, where nobody can be asked."""
+    return False
+
+
+def execution_summary(
+    execution: AgentExecution | None, trajectory_path: str | None, error: str | None = None
+) -> dict[str, object]:
+    """execution_summary builds the result printed by `run --output json`."""
+    if execution is None:
+        return {"success": False, "error": error, "trajectory_file": trajectory_path}
+
+    tool_calls: list[dict[str, object]] = []
+    for step in execution.steps:
+        results = {result.call_id: result for result in step.tool_results or []}
+        for tool_call in step.tool_calls or []:
+            result = results.get(tool_call.call_id)
+            tool_calls.append(
+                {
+                    "step": step.step_number,
+                    "name": tool_call.name,
+                    "success": result.success if result else None,
+                }
+            )
+    return {
+        "success": execution.success,
+        "final_result": execution.final_result,
+        "steps": len(execution.steps),
+        "execution_time": execution.execution_time,
+        "input_tokens": execution.total_tokens.input_tokens if execution.total_tokens else 0,
+        "output_tokens": execution.total_tokens.output_tokens if execution.total_tokens else 0,
+        "tool_calls": tool_calls,
+        "trajectory_file": trajectory_path,
+    }
+
+
 @click.group()
 @click.version_option(version="0.1.0")
 def cli():
@@ -68,6 +168,25 @@ def cli():
 @click.option("--config-file", help="Path to configuration file", default="trae_config.json")
 @click.option("--trajectory-file", "-t", help="Path to save trajectory file")
 @click.option("--patch-path", "-pp", help="Path to patch file")
+@click.option(
+    "--dry-run",
+    is_flag=True,
+    help=f"Report file edits and shell commands without applying them (or set {DRY_RUN_ENV_VAR}=true)",
+)
+@click.option(
+    "--yes",
+    "-y",
+    "auto_approve",
+    is_flag=True,
+    help=f"Run tools listed in confirm_tools without asking (or set {AUTO_APPROVE_ENV_VAR}=true)",
+)
+@click.option(
+    "--output",
+    "output_format",
+    type=click.Choice(["text", "json"]),
+    default="text",
+    help="json runs headless: no live display or prompts, and only a JSON result on stdout",
+)
 def run(
     task: str | None,
     file_path: str | None,
@@ -81,6 +200,9 @@ def run(
     must_patch: bool = False,
     config_file: str = "trae_config.json",
     trajectory_file: str | None = None,
+    dry_run: bool = False,
+    auto_approve: bool = False,
+    output_format: str = "text",
 ):
     """
     Run is the main function of tace. It runs a task using Trae Agent.
@@ -91,28 +213,34 @@ def run(
 
     Return:
         None (it is expected to be ended after calling the run function)
+        With --output json, the process exits with a non-zero code if the task did not succeed.
     """
+    # In headless mode stdout only carries the JSON result, everything else goes to stderr
+    headless = output_format == "json"
+    result_stdout = sys.stdout
+    if headless:
+        sys.stdout = sys.stderr
+        click.get_current_context().call_on_close(lambda: setattr(sys, "stdout", result_stdout))
+    json_stdout = result_stdout if headless else None
 
     if file_path:
         if task:
-            console.print(
-                "[red]Error: Cannot use both a task string and the --file argument.[/red]"
+            exit_with_error(
+                "Error: Cannot use both a task string and the --file argument.", json_stdout
             )
-            sys.exit(1)
         try:
             task = Path(file_path).read_text()
         except FileNotFoundError:
-            console.print(f"[red]Error: File not found: {file_path}[/red]")
-            sys.exit(1)
+            exit_with_error(f"Error: File not found: {file_path}", json_stdout)
     elif not task:
-        console.print(
-            "[red]Error: Must provide either a task string or use the --file argument.[/red]"
+        exit_with_error(
+            "Error: Must provide either a task string or use the --file argument.", json_stdout
         )
-        sys.exit(1)
 
     config = load_config(config_file, provider, model, model_base_url, api_key, max_steps)
+    enable_dry_run(config, dry_run)
     # Create agent
-    agent: TraeAgent = create_agent(config)
+    agent: TraeAgent = create_agent(config, json_stdout)
 
     # Set up trajectory recording
     trajectory_path = None
@@ -127,30 +255,38 @@ def run(
             os.chdir(working_dir)
             console.print(f"[blue]Changed working directory to: {working_dir}[/blue]")
         except Exception as e:
-            console.print(f"[red]Error changing directory: {e}[/red]")
-            sys.exit(1)
+            exit_with_error(f"Error changing directory: {e}", json_stdout, trajectory_path)
     else:
         working_dir = os.getcwd()
 
     # Ensure working directory is an absolute path
     if not Path(working_dir).is_absolute():
-        console.print(
-            f"[red]Working directory must be an absolute path: {working_dir}, it should start with `/`[/red]"
+        exit_with_error(
+            f"Working directory must be an absolute path: {working_dir}, it should start with `/`",
+            json_stdout,
+            trajectory_path,
         )
-        sys.exit(1)
     # Create CLI Console
-    cli_console = CLIConsole(config)
-    cli_console.print_task_details(
-        task,
-        working_dir,
-        config.default_provider,
-        config.model_providers[config.default_provider].model,
-        config.max_steps,
-        config_file,
-        trajectory_path,
-    )
+    cli_console = None if headless else CLIConsole(config)
+    if cli_console is not None:
+        cli_console.print_task_details(
+            task,
+            working_dir,
+            config.default_provider,
+            config.model_providers[config.default_provider].model,
+            config.max_steps,
+            config_file,
+            trajectory_path,
+        )
 
     agent.set_cli_console(cli_console)
+    if config.confirm_tools and not should_auto_approve(auto_approve):
+        agent.set_confirmation_handler(
+            cli_console.confirm_tool_call if cli_console is not None else decline_tool_call
+        )
+    shutdown = ShutdownCoordinator()
+    shutdown.install()
+    click.get_current_context().call_on_close(shutdown.uninstall)
 
     try:
         task_args = {
@@ -160,20 +296,32 @@ def run(
             "patch_path": patch_path,
         }
         agent.new_task(task, task_args)
-        _ = asyncio.run(agent.execute_task())
+        agent.register_shutdown_hooks(shutdown)
+        shutdown.register("save trajectory", lambda: save_interrupted_trajectory(agent))
+        execution = asyncio.run(agent.execute_task())
 
-        console.print(f"\n[green]Trajectory saved to: {trajectory_path}[/green]")
+        if headless:
+            print(json.dumps(execution_summary(execution, trajectory_path)), file=result_stdout)
+        else:
+            console.print(f"\n[green]Trajectory saved to: {trajectory_path}[/green]")
+        # Scripts rely on text mode exiting 0, so only headless runs report failure this way
+        if headless and not execution.success:
+            sys.exit(1)
 
     except KeyboardInterrupt:
-        console.print("\n[yellow]Task execution interrupted by user[/yellow]")
-        if trajectory_path:
-            console.print(f"[blue]Partial trajectory saved to: {trajectory_path}[/blue]")
+        shutdown.shutdown()
+        console.print(f"\n[yellow]{interrupted_message(trajectory_path)}[/yellow]")
+        if headless:
+            summary = execution_summary(None, trajectory_path, "Task execution interrupted by user")
+            print(json.dumps(summary), file=result_stdout)
         sys.exit(1)
     except Exception as e:
         console.print(f"\n[red]Unexpected error: {e}[/red]")
         console.print(traceback.format_exc())
         if trajectory_path:
             console.print(f"[blue]Trajectory saved to: {trajectory_path}[/blue]")
+        if headless:
+            print(json.dumps(execution_summary(None, trajectory_path, str(e))), file=result_stdout)
         sys.exit(1)
 
 
@@ -185,6 +333,18 @@ def run(
 @click.option("--config-file", help="Path to configuration file", default="trae_config.json")
 @click.option("--max-steps", help="Maximum number of execution steps", type=int, default=20)
 @click.option("--trajectory-file", "-t", help="Path to save trajectory file")
+@click.option(
+    "--dry-run",
+    is_flag=True,
+    help=f"Report file edits and shell commands without applying them (or set {DRY_RUN_ENV_VAR}=true)",
+)
+@click.option(
+    "--yes",
+    "-y",
+    "auto_approve",
+    is_flag=True,
+    help=f"Run tools listed in confirm_tools without asking (or set {AUTO_APPROVE_ENV_VAR}=true)",
+)
 def interactive(
     provider: str | None = None,
     model: str | None = None,
@@ -193,6 +353,8 @@ def interactive(
     config_file: str = "trae_config.json",
     max_steps: int | None = None,
     trajectory_file: str | None = None,
+    dry_run: bool = False,
+    auto_approve: bool = False,
 ):
     """
     This function starts an interactive session with Trae Agent.
@@ -200,6 +362,7 @@ def interactive(
         tasks: the task that you want your agent to solve. This is required to be in the input
     """
     config = load_config(config_file, provider, model, model_base_url, api_key, max_steps=max_steps)
+    enable_dry_run(config, dry_run)
 
     console.print(
         Panel(
@@ -215,8 +378,15 @@ def interactive(
 
     # Create agent
     agent = create_agent(config)
+    if config.confirm_tools and not should_auto_approve(auto_approve):
+        agent.set_confirmation_handler(CLIConsole(None).confirm_tool_call)
+    shutdown = ShutdownCoordinator()
+    shutdown.install()
+    click.get_current_context().call_on_close(shutdown.uninstall)
 
     while True:
+        shutdown.reset()
+        trajectory_path = None
         try:
             console.print("\n[bold blue]Task:[/bold blue] ", end="")
             task = input()
@@ -275,13 +445,22 @@ def interactive(
             # Execute the task
             console.print(f"\n[blue]Executing task: {task}[/blue]")
             agent.new_task(task, task_args)
+            agent.register_shutdown_hooks(shutdown)
+            shutdown.register("save trajectory", lambda: save_interrupted_trajectory(agent))
 
             # Configure agent for progress display
             _ = asyncio.run(agent.execute_task())
 
             console.print(f"\n[green]Trajectory saved to: {trajectory_path}[/green]")
+            trajectory_path = None
 
         except KeyboardInterrupt:
+            # Only a task that was running has anything to clean up
+            if trajectory_path:
+                shutdown.shutdown()
+                console.print(f"\n[yellow]{interrupted_message(trajectory_path)}[/yellow]")
+            if shutdown.received_signal == signal.SIGTERM:
+                sys.exit(1)
             console.print("\n[yellow]Use 'exit' or 'quit' to end the session[/yellow]")
         except EOFError:
             console.print("\n[green]Goodbye![/green]")
@@ -374,6 +553,292 @@ def tools():
     console.print(tools_table)
 
 
+@cli.command()
+@click.argument("tool_calls_file")
+@click.option("--tool", "tool_name", help="Only replay calls to this tool")
+@click.option("--from", "from_index", type=int, help="Index of the first call to replay (1-based)")
+@click.option("--to", "to_index", type=int, help="Index of the last call to replay (inclusive)")
+@click.option("--config-file", help="Path to configuration file", default="trae_config.json")
+@click.option(
+    "--max-output",
+    type=int,
+    help="Character budget of each tool result (default: max_tool_output from the config file)",
+)
+@click.option(
+    "--yes",
+    "-y",
+    "auto_approve",
+    is_flag=True,
+    help=f"Run tools listed in confirm_tools without asking (or set {AUTO_APPROVE_ENV_VAR}=true)",
+)
+def replay(
+    tool_calls_file: str,
+    tool_name: str | None = None,
+    from_index: int | None = None,
+    to_index: int | None = None,
+    config_file: str = "trae_config.json",
+    max_output: int | None = None,
+    auto_approve: bool = False,
+):
+    """
+    Re-execute recorded tool calls and diff the fresh output against the recorded one.
+    Args:
+        tool_calls_file: a JSONL file written by TrajectoryRecorder.export_tool_calls,
+            or a trajectory file
+    """
+    from .tools import tools_registry
+
+    first = from_index or 1
+    try:
+        if tool_calls_file.endswith(".jsonl"):
+            with open(tool_calls_file, "r", encoding="utf-8") as f:
+                records = [json.loads(line) for line in f if line.strip()]
+        else:
+            records = TrajectoryRecorder.load(tool_calls_file).get_tool_call_records()
+        last = to_index or len(records)
+        selected = [
+            (index, record)
+            for index, record in enumerate(records, start=1)
+            if first <= index <= last and (tool_name is None or record["name"] == tool_name)
+        ]
+        tool_calls = [
+            ToolCall(name=record["name"], call_id=f"replay_{index}", arguments=record["arguments"])
+            for index, record in selected
+        ]
+    except FileNotFoundError:
+        console.print(f"[red]Error: File not found: {tool_calls_file}[/red]")
+        sys.exit(1)
+    except (json.JSONDecodeError, KeyError, TypeError) as e:
+        console.print(
+            f"[red]Error: Could not read tool calls from {tool_calls_file}: {escape(repr(e))}[/red]"
+        )
+        sys.exit(1)
+    if not selected:
+        console.print("[yellow]No tool calls matched the given filters[/yellow]")
+        return
+
+    config = Config(config_file)
+    # Recorded results were truncated to the run's budget, so replayed ones must be too
+    if max_output is None:
+        max_output = config.max_tool_output
+    tools = [tool_cls() for tool_cls in tools_registry.values()]
+    confirmation_handler = None
+    if config.confirm_tools and not should_auto_approve(auto_approve):
+        confirmation_handler = CLIConsole(None).confirm_tool_call
+    executor = ToolExecutor(
+        tools,
+        confirmation_handler=confirmation_handler,
+        confirm_tools=config.confirm_tools,
+        max_output=max_output,
+        dry_run=config.dry_run,
+    )
+
+    async def replay_all() -> list[ToolResult]:
+        try:
+            return await executor.sequential_tool_call(tool_calls)
+        finally:
+            # Close the tools while the loop that owns their subprocesses is still running
+            for tool in tools:
+                await tool.aclose()
+
+    results = asyncio.run(replay_all())
+
+    mismatches = 0
+    for (index, record), result in zip(selected, results, strict=True):
+        recorded = (record.get("result") or "") + (record.get("error") or "")
+        replayed = (result.result or "") + (result.error or "")
+        diff = list(
+            difflib.unified_diff(
+                recorded.splitlines(),
+                replayed.splitlines(),
+                fromfile="recorded",
+                tofile="replayed",
+                lineterm="",
+            )
+        )
+        if not diff:
+            console.print(f"[green]#{index} {record['name']}: identical[/green]")
+            continue
+        mismatches += 1
+        console.print(f"[yellow]#{index} {record['name']}: output differs[/yellow]")
+        console.print("\n".join(diff), markup=False, highlight=False)
+
+    console.print(f"\n{len(selected) - mismatches}/{len(selected)} tool calls matched")
+    if mismatches:
+        sys.exit(1)
+
+
+@cli.command(name="export-md")
+@click.argument("trajectory_file")
+@click.option("--output", "-o", help="Path of the Markdown file (default: next to the trajectory)")
+def export_md(trajectory_file: str, output: str | None = None):
+    """
+    Export a trajectory as a readable Markdown document.
+    Args:
+        trajectory_file: a trajectory file written by 'run' or 'interactive'
+    """
+    try:
+        recorder = TrajectoryRecorder.load(trajectory_file)
+    except FileNotFoundError:
+        console.print(f"[red]Error: File not found: {trajectory_file}[/red]")
+        sys.exit(1)
+    except json.JSONDecodeError as e:
+        console.print(f"[red]Error: Could not read trajectory {trajectory_file}: {e}[/red]")
+        sys.exit(1)
+
+    output_path = Path(output) if output else Path(trajectory_file).with_suffix(".md")
+    recorder.export_markdown(output_path)
+    console.print(f"[green]Trajectory exported to {output_path}[/green]")
+
+
+# Characters of context shown on each side of a search match
+SEARCH_CONTEXT_CHARS: int = 60
+
+
+@cli.command()
+@click.argument("query")
+@click.option(
+    "--dir",
+    "trajectory_dir",
+    default="trajectories",
+    help="Directory containing trajectory files",
+)
+@click.option("--role", type=click.Choice(["user", "assistant"]), help="Only search these messages")
+@click.option(
+    "--since", type=click.DateTime(["%Y-%m-%d"]), help="Only messages on or after this date"
+)
+@click.option(
+    "--until", type=click.DateTime(["%Y-%m-%d"]), help="Only messages on or before this date"
+)
+def search(
+    query: str,
+    trajectory_dir: str = "trajectories",
+    role: str | None = None,
+    since: datetime | None = None,
+    until: datetime | None = None,
+):
+    """
+    Search the messages of all stored trajectories for a case-insensitive substring.
+    Args:
+        query: the text to look for
+    """
+    directory = Path(trajectory_dir)
+    if not directory.is_dir():
+        console.print(f"[red]Error: Directory not found: {trajectory_dir}[/red]")
+        sys.exit(1)
+
+    index = TrajectoryIndex(directory)
+    index.refresh()
+
+    hits = 0
+    for trajectory_file in index.candidates(query):
+        try:
+            messages = TrajectoryRecorder.load(str(trajectory_file)).get_messages()
+        except NOT_A_TRAJECTORY_ERRORS:
+            # Not a trajectory file
+            continue
+
+        for message in messages:
+            if role is not None and message["role"] != role:
+                continue
+            if since is not None or until is not None:
+                try:
+                    timestamp = datetime.fromisoformat(message["timestamp"])
+                except (TypeError, ValueError):
+                    # A message without a usable timestamp cannot be shown to be in range
+                    continue
+                if timestamp.tzinfo is not None:
+                    timestamp = timestamp.astimezone().replace(tzinfo=None)
+                if (since is not None and timestamp < since) or (
+                    until is not None and timestamp >= until + timedelta(days=1)
+                ):
+                    continue
+
+            content: str = message["content"] or ""
+            position = content.lower().find(query.lower())
+            if position < 0:
+                continue
+
+            hits += 1
+            start = max(0, position - SEARCH_CONTEXT_CHARS)
+            end = min(len(content), position + len(query) + SEARCH_CONTEXT_CHARS)
+            snippet = (
+                ("..." if start > 0 else "")
+                + escape(content[start:position])
+                + f"[bold yellow]{escape(content[position : position + len(query)])}[/bold yellow]"
+                + escape(content[position + len(query) : end])
+                + ("..." if end < len(content) else "")
+            ).replace("\n", " ")
+            console.print(
+                f"[cyan]{trajectory_file.stem}[/cyan] {message['timestamp']} "
+                + escape(f"[{message['role']}]")
+            )
+            console.print(f"  {snippet}")
+
+    console.print(f"\n{hits} matching message{'s' if hits != 1 else ''}")
+
+
+@cli.group()
+def audit():
+    """Inspect the audit log of tool calls made across all runs."""
+    pass
+
+
+def read_audit_log(config_file: str) -> list[str]:
+    """read_audit_log returns the lines of the audit log configured in config_file."""
+    config = Config(config_file)
+    if not config.audit_log:
+        console.print("[red]Error: The audit log is disabled in the configuration[/red]")
+        sys.exit(1)
+    audit_path = AuditLog(config.audit_log).path
+    try:
+        with open(audit_path, "r", encoding="utf-8") as f:
+            return [line.rstrip("\n") for line in f if line.strip()]
+    except FileNotFoundError:
+        console.print(f"[yellow]No audit log found at {audit_path}[/yellow]")
+        return []
+
+
+def print_audit_entry(line: str) -> None:
+    """print_audit_entry prints an audit log line as time, session, status, tool and arguments."""
+    try:
+        entry = json.loads(line)
+        status = "ok" if entry["success"] else "failed"
+        arguments = json.dumps(entry["arguments"], ensure_ascii=False)
+        text = f"{entry['timestamp']} {entry['session_id']} {status} {entry['tool']} {arguments}"
+    except (json.JSONDecodeError, KeyError, TypeError):
+        text = line
+    console.print(text, markup=False, highlight=False)
+
+
+@audit.command(name="tail")
+@click.option("--config-file", help="Path to configuration file", default="trae_config.json")
+@click.option("--lines", "-n", type=int, default=20, help="Number of entries to show")
+def audit_tail(config_file: str = "trae_config.json", lines: int = 20):
+    """Show the most recent tool calls in the audit log."""
+    for line in read_audit_log(config_file)[-lines:] if lines > 0 else []:
+        print_audit_entry(line)
+
+
+@audit.command(name="grep")
+@click.argument("pattern")
+@click.option("--config-file", help="Path to configuration file", default="trae_config.json")
+def audit_grep(pattern: str, config_file: str = "trae_config.json"):
+    """
+    Show the audit log entries matching a regular expression.
+    Args:
+        pattern: the regular expression, matched against the raw JSON line of each entry
+    """
+    try:
+        regex = re.compile(pattern)
+    except re.error as e:
+        console.print(f"[red]Error: Invalid pattern {escape(pattern)}: {e}[/red]")
+        sys.exit(1)
+    for line in read_audit_log(config_file):
+        if regex.search(line):
+            print_audit_entry(line)
+
+
 def main():
     """Main entry point for the CLI."""
     cli()
@@ -8,10 +8,18 @@ from typing import Type
 from .base import Tool, ToolCall, ToolExecutor, ToolResult
 from .bash_tool import BashTool
 from .ckg_tool import CKGTool
+from .dependency_graph_tool import DependencyGraphTool
 from .edit_tool import TextEditorTool
+from .file_hash_tool import FileHashTool
+from .format_code_tool import FormatCodeTool
 from .json_edit_tool import JSONEditTool
+from .read_file_tool import ReadFileTool
 from .sequential_thinking_tool import SequentialThinkingTool
 from .task_done_tool import TaskDoneTool
+from .venv_tool import ActivateVenvTool, CreateVenvTool, ListVenvsTool
+from .watch_path_tool import WatchPathTool
+from .working_directory_tool import WorkingDirectoryTool
+from .write_file_tool import WriteFileTool
 
 __all__ = [
     "Tool",
@@ -24,6 +32,16 @@ __all__ = [
     "SequentialThinkingTool",
     "TaskDoneTool",
     "CKGTool",
+    "ReadFileTool",
+    "WriteFileTool",
+    "WatchPathTool",
+    "FileHashTool",
+    "DependencyGraphTool",
+    "WorkingDirectoryTool",
+    "CreateVenvTool",
+    "ActivateVenvTool",
+    "ListVenvsTool",
+    "FormatCodeTool",
 ]
 
 tools_registry: dict[str, Type[Tool]] = {
@@ -33,4 +51,14 @@ tools_registry: dict[str, Type[Tool]] = {
     "sequentialthinking": SequentialThinkingTool,
     "task_done": TaskDoneTool,
     "ckg": CKGTool,
+    "read_file": ReadFileTool,
+    "write_file": WriteFileTool,
+    "watch_path": WatchPathTool,
+    "file_hash": FileHashTool,
+    "dependency_graph": DependencyGraphTool,
+    "working_directory": WorkingDirectoryTool,
+    "create_venv": CreateVenvTool,
+    "activate_venv": ActivateVenvTool,
+    "list_venvs": ListVenvsTool,
+    "format_code": FormatCodeTool,
 }
@@ -4,14 +4,28 @@
 """Base classes for tools and tool calling."""
 
 import asyncio
+import os
 from abc import ABC, abstractmethod
+from collections.abc import Callable
 from dataclasses import dataclass, field
 from functools import cached_property
+from pathlib import Path
 from typing import TypeAlias, override
 
+from .run import truncate_output
+
 ParamSchemaValue: TypeAlias = str | list[str] | bool | dict[str, object]
 Property: TypeAlias = dict[str, ParamSchemaValue]
 
+# When set to a truthy value, the config enables dry-run mode; see `ToolExecutor`'s dry_run
+DRY_RUN_ENV_VAR: str = "TRAE_DRY_RUN"
+DRY_RUN_NOTICE: str = "[DRY RUN] no changes applied."
+
+
+def is_dry_run() -> bool:
+    """Check whether the TRAE_DRY_RUN environment variable asks for dry-run mode."""
+    return os.getenv(DRY_RUN_ENV_VAR, "").lower() in ("1", "true", "yes")
+
 
 class ToolError(Exception):
     """Base class for tool errors."""
@@ -59,6 +73,12 @@ class ToolCall:
         return f"ToolCall(name={self.name}, arguments={self.arguments}, call_id={self.call_id}, id={self.id})"
 
 
+# Decides whether a tool call may proceed, e.g. by asking the user; returns False to decline it
+ConfirmationHandler: TypeAlias = Callable[[ToolCall], bool]
+# Called with every finished tool call and its result, e.g. to write an audit log
+ToolCallObserver: TypeAlias = Callable[[ToolCall, ToolResult], None]
+
+
 @dataclass
 class ToolParameter:
     """Tool parameter definition."""
@@ -71,11 +91,67 @@ class ToolParameter:
     required: bool = True
 
 
+# Accepted by every tool and applied by ToolExecutor, so tools do not declare it themselves
+MAX_OUTPUT_PARAMETER = ToolParameter(
+    name="max_output",
+    type="integer",
+    description="Maximum number of characters of output to return. Longer output keeps its beginning and end with the middle omitted. Defaults to the configured limit.",
+    required=False,
+)
+
+
+class DirectoryStack:
+    """Working directories the agent moved through, with the current one on top.
+
+    Relative paths given to tools resolve against the current directory.
+    """
+
+    def __init__(self, start: Path | None = None):
+        self._stack: list[Path] = [start or Path.cwd()]
+
+    @property
+    def current(self) -> Path:
+        return self._stack[-1]
+
+    @property
+    def directories(self) -> list[Path]:
+        """The stack from the current directory down, like `dirs` in a shell."""
+        return self._stack[::-1]
+
+    def resolve(self, path: Path) -> Path:
+        """Resolve a relative path against the current directory, normalizing `..` parts."""
+        return Path(os.path.normpath(self.current / path))
+
+    def change(self, path: Path) -> None:
+        """Replace the current directory, like `cd`."""
+        self._stack[-1] = self._directory(path)
+
+    def push(self, path: Path) -> None:
+        """Make `path` the current directory, keeping the previous one below it, like `pushd`."""
+        self._stack.append(self._directory(path))
+
+    def pop(self) -> None:
+        """Return to the directory below the current one, like `popd`."""
+        if len(self._stack) == 1:
+            raise ToolError("The directory stack is empty, there is no directory to return to.")
+        _ = self._stack.pop()
+
+    def _directory(self, path: Path) -> Path:
+        directory = self.resolve(path)
+        if not directory.exists():
+            raise ToolError(f"The directory {directory} does not exist.")
+        if not directory.is_dir():
+            raise ToolError(f"The path {directory} is not a directory.")
+        return directory
+
+
 class Tool(ABC):
     """Base class for all tools."""
 
     def __init__(self, model_provider: str | None = None):
         self._model_provider = model_provider
+        # Shared by the tools of one executor, see ToolExecutor
+        self.directory_stack: DirectoryStack | None = None
 
     @cached_property
     def model_provider(self) -> str | None:
@@ -117,6 +193,36 @@ class Tool(ABC):
         """Execute the tool with given parameters."""
         pass
 
+    def resolve_path(self, path: str) -> Path:
+        """Resolve a relative path against the current directory of the directory stack.
+
+        Tools used without a directory stack get the path back unchanged and reject it if it
+        is relative.
+        """
+        if self.directory_stack is None:
+            return Path(path)
+        return self.directory_stack.resolve(Path(path))
+
+    async def aclose(self) -> None:
+        """Release any resources held by the tool. Override if the tool owns processes or handles.
+
+        This runs inside the event loop, so asyncio subprocesses can be closed before their loop is.
+        """
+        pass
+
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:  # pyright: ignore[reportUnusedParameter]
+        """Check whether a call with these arguments leaves files and processes untouched."""
+        return False
+
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report what a call that is not read-only would do, without doing it.
+
+        Called instead of `execute` in dry-run mode. Override to describe the change in detail.
+        """
+        return ToolExecResult(
+            output=f"{DRY_RUN_NOTICE} This '{self.name}' call was not executed:\n{arguments}"
+        )
+
     def json_definition(self) -> dict[str, object]:
         return {
             "name": self.name,
@@ -133,7 +239,7 @@ class Tool(ABC):
         properties: dict[str, Property] = {}
         required: list[str] = []
 
-        for param in self.parameters:
+        for param in [*self.parameters, MAX_OUTPUT_PARAMETER]:
             param_schema: Property = {
                 "type": param.type,
                 "description": param.description,
@@ -178,9 +284,43 @@ class Tool(ABC):
 class ToolExecutor:
     """Tool executor that manages tool execution."""
 
-    def __init__(self, tools: list[Tool]):
+    def __init__(
+        self,
+        tools: list[Tool],
+        confirmation_handler: ConfirmationHandler | None = None,
+        confirm_tools: list[str] | None = None,
+        directory_stack: DirectoryStack | None = None,
+        max_output: int | None = None,
+        observer: ToolCallObserver | None = None,
+        dry_run: bool = False,
+    ):
+        """Initialize the tool executor.
+
+        Args:
+            tools: Tools that can be called.
+            confirmation_handler: Optional hook asked to approve calls to the tools in
+                confirm_tools. Calls are not gated when no handler is given.
+            confirm_tools: Names of tools whose non read-only calls need approval.
+            directory_stack: Working directories shared by the tools. A new stack starting
+                at the process's working directory is used when none is given.
+            max_output: Character budget shared by each tool result's output and error.
+                Longer text keeps its head and tail around an omission marker. A `max_output`
+                argument in a tool call overrides it. None or 0 disables the limit.
+            observer: Optional hook called with every tool call and its result, including
+                calls that were declined or failed.
+            dry_run: Only report what calls would do. Calls that are not read-only go to
+                the tool's `dry_run` instead of `execute`.
+        """
         self._tools = tools
+        self._directory_stack = directory_stack or DirectoryStack()
+        for tool in tools:
+            tool.directory_stack = self._directory_stack
         self._tool_map: dict[str, Tool] | None = None
+        self._confirmation_handler = confirmation_handler
+        self._confirm_tools = {self._normalize_name(name) for name in confirm_tools or []}
+        self._max_output = max_output
+        self._observer = observer
+        self._dry_run = dry_run
 
     def _normalize_name(self, name: str) -> str:
         """Normalize tool name by making it lowercase and removing underscores."""
@@ -194,6 +334,12 @@ class ToolExecutor:
 
     async def execute_tool_call(self, tool_call: ToolCall) -> ToolResult:
         """Execute a tool call."""
+        result = await self._execute_tool_call(tool_call)
+        if self._observer is not None:
+            self._observer(tool_call, result)
+        return result
+
+    async def _execute_tool_call(self, tool_call: ToolCall) -> ToolResult:
         normalized_name = self._normalize_name(tool_call.name)
         if normalized_name not in self.tools:
             return ToolResult(
@@ -206,13 +352,36 @@ class ToolExecutor:
 
         tool = self.tools[normalized_name]
 
+        if (
+            self._confirmation_handler is not None
+            and normalized_name in self._confirm_tools
+            and not tool.is_read_only(tool_call.arguments)
+            and not self._confirmation_handler(tool_call)
+        ):
+            return ToolResult(
+                name=tool_call.name,
+                success=False,
+                error=f"The user declined to run this '{tool_call.name}' call. Do not retry it unchanged; ask the user or take a different approach.",
+                call_id=tool_call.call_id,
+                id=tool_call.id,
+            )
+
         try:
-            tool_exec_result = await tool.execute(tool_call.arguments)
+            if self._dry_run and not tool.is_read_only(tool_call.arguments):
+                tool_exec_result = await tool.dry_run(tool_call.arguments)
+            else:
+                tool_exec_result = await tool.execute(tool_call.arguments)
+            output, error = tool_exec_result.output, tool_exec_result.error
+            max_output = tool_call.arguments.get("max_output")
+            if not isinstance(max_output, int) or max_output <= 0:
+                max_output = self._max_output
+            if max_output:
+                output, error = truncate_output(output, error, max_output)
             return ToolResult(
                 name=tool_call.name,
                 success=tool_exec_result.error_code == 0,
-                result=tool_exec_result.output,
-                error=tool_exec_result.error,
+                result=output,
+                error=error,
                 call_id=tool_call.call_id,
                 id=tool_call.id,
             )
@@ -10,10 +10,57 @@
 # This modified file is released under the same license.
 
 import asyncio
+import contextlib
 import os
+import re
+import shlex
+import signal
+from pathlib import Path
 from typing import override
 
-from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .base import (
+    DRY_RUN_NOTICE,
+    Tool,
+    ToolCallArguments,
+    ToolError,
+    ToolExecResult,
+    ToolParameter,
+)
+
+ENV_VAR_NAME_PATTERN = re.compile(r"^[A-Za-z_][A-Za-z0-9_]*$")
+# Variables kept when a command runs with `clean_env`, in addition to `env_allowlist`
+CLEAN_ENV_BASE_VARS: list[str] = ["PATH", "HOME"]
+# Seconds background processes get to exit on SIGTERM before they are killed
+KILL_GRACE_PERIOD: float = 1.0
+# Commands that never write files on their own; they still run in dry-run mode
+READ_ONLY_COMMANDS: set[str] = {
+    "cat",
+    "diff",
+    "echo",
+    "grep",
+    "head",
+    "ls",
+    "pwd",
+    "stat",
+    "tail",
+    "wc",
+    "which",
+}
+READ_ONLY_GIT_SUBCOMMANDS: set[str] = {"diff", "log", "show", "status"}
+# Options that make find delete files or run other commands
+FIND_UNSAFE_OPTIONS: set[str] = {
+    "-delete",
+    "-exec",
+    "-execdir",
+    "-ok",
+    "-okdir",
+    "-fls",
+    "-fprint",
+    "-fprint0",
+    "-fprintf",
+}
+# Operators that may separate read-only commands; any other one, e.g. `>`, is not read-only
+READ_ONLY_OPERATORS: set[str] = {"|", "||", "&&", ";", "<"}
 
 
 class _BashSession:
@@ -70,6 +117,35 @@ class _BashSession:
             return
         self._process.terminate()
 
+    def _signal_group(self, signum: int) -> bool:
+        """Send a signal to the shell's process group; return False if the group is gone."""
+        assert self._process is not None
+        try:
+            # the shell was started with `os.setsid`, so its pid is also its process group id
+            os.killpg(self._process.pid, signum)
+        except ProcessLookupError:
+            return False
+        return True
+
+    async def shutdown(self) -> None:
+        """Terminate the bash shell together with any background processes it spawned.
+
+        Processes still running KILL_GRACE_PERIOD seconds after SIGTERM are sent SIGKILL. Waiting
+        for the shell to exit lets the loop close its pipes, which it cannot do once it is closed.
+        """
+        if self._process is None:
+            return
+        if os.name == "nt":
+            if self._process.returncode is None:
+                self._process.terminate()
+        elif self._signal_group(signal.SIGTERM):
+            with contextlib.suppress(TimeoutError):
+                async with asyncio.timeout(KILL_GRACE_PERIOD):
+                    while self._signal_group(0):
+                        await asyncio.sleep(0.05)
+            _ = self._signal_group(signal.SIGKILL)
+        _ = await self._process.wait()
+
     async def run(self, command: str) -> ToolExecResult:
         """Execute a command in the bash shell."""
         if not self._started or self._process is None:
@@ -170,8 +246,11 @@ class BashTool(Tool):
 * You have access to a mirror of common linux and python packages via apt and pip.
 * State is persistent across command calls and discussions with the user.
 * To inspect a particular line range of a file, e.g. lines 10-25, try 'sed -n 10,25p /path/to/the/file'.
-* Please avoid commands that may produce a very large amount of output.
+* Please avoid commands that may produce a very large amount of output. Long output is shortened to its beginning and end, marked with `[... N lines omitted ...]`.
 * Please run long lived commands in the background, e.g. 'sleep 10 &' or start a server in the background.
+* Use `cwd` to run a single command in another directory, and `env` to set environment variables for it.
+* In dry-run mode only read-only commands run: `ls`, `cat`, `head`, `tail`, `grep`, `wc`, `diff`, `stat`, `pwd`, `echo`, `which`, `find` without actions and `git status/diff/log/show`, optionally chained with pipes, `&&`, `||` or `;`. Other commands are reported without being executed.
+* Set `clean_env` to run without the inherited environment: only PATH, HOME and the variables named in `env_allowlist` are kept. Variables given in `env` always take precedence over inherited ones.
 """
 
     @override
@@ -193,8 +272,78 @@ class BashTool(Tool):
                 description="Set to true to restart the bash session.",
                 required=restart_required,
             ),
+            ToolParameter(
+                name="cwd",
+                type="string",
+                description="Absolute path of the directory to run this command in. Does not change the directory of later commands.",
+                required=False,
+            ),
+            ToolParameter(
+                name="env",
+                type="array",
+                description="Environment variables to set for this command, as `NAME=value` strings. They override inherited variables of the same name.",
+                items={"type": "string"},
+                required=False,
+            ),
+            ToolParameter(
+                name="clean_env",
+                type="boolean",
+                description="Set to true to run this command without the inherited environment. Only PATH, HOME, the variables in `env_allowlist` and those in `env` are set.",
+                required=False,
+            ),
+            ToolParameter(
+                name="env_allowlist",
+                type="array",
+                description="Names of inherited environment variables to keep when `clean_env` is true.",
+                items={"type": "string"},
+                required=False,
+            ),
         ]
 
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        """Check whether the command only chains allowlisted commands that do not write files."""
+        command = arguments.get("command")
+        if not isinstance(command, str) or arguments.get("restart"):
+            return False
+        # Command substitution and multiple lines could hide any other command
+        if "$(" in command or "`" in command or "\n" in command:
+            return False
+        try:
+            lexer = shlex.shlex(command, posix=True, punctuation_chars=True)
+            lexer.whitespace_split = True
+            tokens = list(lexer)
+        except ValueError:
+            return False
+
+        simple_commands: list[list[str]] = [[]]
+        for token in tokens:
+            if token in READ_ONLY_OPERATORS:
+                simple_commands.append([])
+            elif token and all(char in lexer.punctuation_chars for char in token):
+                return False
+            else:
+                simple_commands[-1].append(token)
+        return all(self._is_read_only_command(words) for words in simple_commands if words)
+
+    def _is_read_only_command(self, words: list[str]) -> bool:
+        program, args = words[0], words[1:]
+        if program == "find":
+            return not FIND_UNSAFE_OPTIONS.intersection(args)
+        if program == "git":
+            return (
+                bool(args)
+                and args[0] in READ_ONLY_GIT_SUBCOMMANDS
+                and not any(arg.startswith("--output") for arg in args)
+            )
+        return program in READ_ONLY_COMMANDS
+
+    @override
+    async def aclose(self) -> None:
+        if self._session is not None:
+            await self._session.shutdown()
+            self._session = None
+
     @override
     async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
         if arguments.get("restart"):
@@ -219,6 +368,92 @@ class BashTool(Tool):
                 error_code=-1,
             )
         try:
-            return await self._session.run(command)
+            wrapped_command = self._apply_environment(command, arguments)
+        except ToolError as e:
+            return ToolExecResult(error=e.message, error_code=-1)
+        try:
+            return await self._session.run(wrapped_command)
         except Exception as e:
             return ToolExecResult(error=f"Error running bash command: {e}", error_code=-1)
+
+    @override
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report the command without running it or touching the session."""
+        if arguments.get("restart"):
+            return ToolExecResult(output=f"{DRY_RUN_NOTICE} The bash session was not restarted.")
+        command = str(arguments["command"]) if "command" in arguments else None
+        if command is None:
+            return ToolExecResult(
+                error=f"No command provided for the {self.get_name()} tool",
+                error_code=-1,
+            )
+        try:
+            _ = self._apply_environment(command, arguments)
+        except ToolError as e:
+            return ToolExecResult(error=e.message, error_code=-1)
+        return ToolExecResult(
+            output=f"{DRY_RUN_NOTICE} The following command was not executed:\n{command}"
+        )
+
+    def _apply_environment(self, command: str, arguments: ToolCallArguments) -> str:
+        """Wrap `command` so that it runs with the requested `cwd` and environment.
+
+        Commands already run in a subshell, so neither `cd` nor `export` leak into later calls.
+        """
+        cwd = arguments.get("cwd")
+        env = self._parse_env(arguments.get("env"))
+        clean_env = arguments.get("clean_env") is True
+        if cwd is None and not env and not clean_env:
+            return command
+        if os.name == "nt":
+            raise ToolError("`cwd`, `env` and `clean_env` are not supported on Windows.")
+
+        lines: list[str] = []
+        if cwd is not None:
+            cwd_path = Path(str(cwd))
+            if not cwd_path.is_absolute():
+                raise ToolError(f"`cwd` must be an absolute path, got {cwd}")
+            if not cwd_path.is_dir():
+                raise ToolError(f"`cwd` {cwd} does not exist or is not a directory.")
+            lines.append(f"cd {shlex.quote(str(cwd_path))} || exit $?")
+
+        if clean_env:
+            allowlist = self._parse_names(arguments.get("env_allowlist"))
+            kept = {
+                name: os.environ[name]
+                for name in CLEAN_ENV_BASE_VARS + allowlist
+                if name in os.environ
+            }
+            assignments = " ".join(
+                f"{name}={shlex.quote(value)}" for name, value in (kept | env).items()
+            )
+            lines.append(f"env -i {assignments} /bin/bash -c {shlex.quote(command)}")
+        else:
+            lines.extend(f"export {name}={shlex.quote(value)}" for name, value in env.items())
+            lines.append(command)
+        return "\n".join(lines)
+
+    def _parse_env(self, env: object) -> dict[str, str]:
+        """Parse `NAME=value` strings into a dict, validating the variable names."""
+        if env is None:
+            return {}
+        if not isinstance(env, list):
+            raise ToolError("`env` must be a list of `NAME=value` strings.")
+        parsed: dict[str, str] = {}
+        for item in env:
+            name, sep, value = str(item).partition("=")
+            if not sep or not ENV_VAR_NAME_PATTERN.match(name):
+                raise ToolError(f"Invalid `env` entry {item!r}, expected `NAME=value`.")
+            parsed[name] = value
+        return parsed
+
+    def _parse_names(self, names: object) -> list[str]:
+        """Validate the variable names given in `env_allowlist`."""
+        if names is None:
+            return []
+        if not isinstance(names, list):
+            raise ToolError("`env_allowlist` must be a list of variable names.")
+        for name in names:
+            if not ENV_VAR_NAME_PATTERN.match(str(name)):
+                raise ToolError(f"Invalid variable name {name!r} in `env_allowlist`.")
+        return [str(name) for name in names]
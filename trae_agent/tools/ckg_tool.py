@@ -78,6 +78,10 @@ class CKGTool(Tool):
             ),
         ]
 
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
     @override
     async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
         command = str(arguments.get("command")) if "command" in arguments else None
@@ -100,7 +104,7 @@ class CKGTool(Tool):
             )
         print_body = bool(arguments.get("print_body")) if "print_body" in arguments else True
 
-        codebase_path = Path(path)
+        codebase_path = self.resolve_path(path)
         if not codebase_path.exists():
             return ToolExecResult(
                 error=f"Codebase path {path} does not exist",
@@ -145,20 +149,16 @@ class CKGTool(Tool):
 
         output = f"Found {len(entries)} functions named {identifier}:\n"
 
-        index = 1
-        for entry in entries:
-            output += f"{index}. {entry.file_path}:{entry.start_line}-{entry.end_line}\n"
+        for index, entry in enumerate(entries, start=1):
+            entry_output = f"{index}. {entry.file_path}:{entry.start_line}-{entry.end_line}\n"
             if print_body:
-                output += f"{entry.body}\n\n"
+                entry_output += f"{entry.body}\n\n"
 
-            index += 1
-
-            if len(output) > MAX_RESPONSE_LEN:
-                output = (
-                    output[:MAX_RESPONSE_LEN]
-                    + f"\n<response clipped> {len(entries) - index + 1} more entries not shown"
-                )
+            # Drop whole entries rather than cutting one off halfway
+            if index > 1 and len(output) + len(entry_output) > MAX_RESPONSE_LEN:
+                output += f"<response clipped> {len(entries) - index + 1} more entries not shown"
                 break
+            output += entry_output
 
         return output
 
@@ -174,24 +174,20 @@ class CKGTool(Tool):
 
         output = f"Found {len(entries)} classes named {identifier}:\n"
 
-        index = 1
-        for entry in entries:
-            output += f"{index}. {entry.file_path}:{entry.start_line}-{entry.end_line}\n"
+        for index, entry in enumerate(entries, start=1):
+            entry_output = f"{index}. {entry.file_path}:{entry.start_line}-{entry.end_line}\n"
             if entry.fields:
-                output += f"Fields:\n{entry.fields}\n"
+                entry_output += f"Fields:\n{entry.fields}\n"
             if entry.methods:
-                output += f"Methods:\n{entry.methods}\n"
+                entry_output += f"Methods:\n{entry.methods}\n"
             if print_body:
-                output += f"{entry.body}\n\n"
+                entry_output += f"{entry.body}\n\n"
 
-            index += 1
-
-            if len(output) > MAX_RESPONSE_LEN:
-                output = (
-                    output[:MAX_RESPONSE_LEN]
-                    + f"\n<response clipped> {len(entries) - index + 1} more entries not shown"
-                )
+            # Drop whole entries rather than cutting one off halfway
+            if index > 1 and len(output) + len(entry_output) > MAX_RESPONSE_LEN:
+                output += f"<response clipped> {len(entries) - index + 1} more entries not shown"
                 break
+            output += entry_output
 
         return output
 
@@ -207,19 +203,15 @@ class CKGTool(Tool):
 
         output = f"Found {len(entries)} class methods named {identifier}:\n"
 
-        index = 1
-        for entry in entries:
-            output += f"{index}. {entry.file_path}:{entry.start_line}-{entry.end_line} within class {entry.parent_class}\n"
+        for index, entry in enumerate(entries, start=1):
+            entry_output = f"{index}. {entry.file_path}:{entry.start_line}-{entry.end_line} within class {entry.parent_class}\n"
             if print_body:
-                output += f"{entry.body}\n\n"
+                entry_output += f"{entry.body}\n\n"
 
-            index += 1
-
-            if len(output) > MAX_RESPONSE_LEN:
-                output = (
-                    output[:MAX_RESPONSE_LEN]
-                    + f"\n<response clipped> {len(entries) - index + 1} more entries not shown"
-                )
+            # Drop whole entries rather than cutting one off halfway
+            if index > 1 and len(output) + len(entry_output) > MAX_RESPONSE_LEN:
+                output += f"<response clipped> {len(entries) - index + 1} more entries not shown"
                 break
+            output += entry_output
 
         return output
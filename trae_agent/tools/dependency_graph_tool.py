@@ -0,0 +1,314 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool for rendering a project's dependency tree as a DOT graph."""
+
+import json
+import re
+import shlex
+import shutil
+import tomllib
+from collections import deque
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import override
+
+from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .run import maybe_truncate, run
+
+# Checked in this order, so a Rust project with a package.json for its docs counts as Cargo
+PROJECT_MANIFESTS: dict[str, str] = {
+    "Cargo.toml": "Cargo",
+    "package.json": "npm",
+    "pyproject.toml": "pip",
+}
+PROJECT_TOOLS: dict[str, str] = {"Cargo": "cargo", "npm": "npm", "pip": "pip"}
+DEPENDENCY_COMMAND_TIMEOUT: float = 120.0  # seconds
+
+# The distribution name at the start of a requirement such as `requests[socks]>=2`
+REQUIREMENT_NAME = re.compile(r"^\s*([A-Za-z0-9][A-Za-z0-9._-]*)")
+
+
+@dataclass
+class DependencyGraph:
+    """Packages keyed by an id unique to one version, and the packages each one depends on."""
+
+    roots: list[str] = field(default_factory=list)
+    labels: dict[str, str] = field(default_factory=dict)
+    edges: dict[str, list[str]] = field(default_factory=dict)
+
+    def add_edge(self, source: str, target: str) -> None:
+        targets = self.edges.setdefault(source, [])
+        if target not in targets:
+            targets.append(target)
+
+
+def normalize_distribution_name(name: str) -> str:
+    """Normalize a Python distribution name so `Foo_Bar` and `foo-bar` compare equal."""
+    return re.sub(r"[-_.]+", "-", name).lower()
+
+
+def dot_quote(label: str) -> str:
+    return '"' + label.replace("\\", "\\\\").replace('"', '\\"') + '"'
+
+
+class DependencyGraphTool(Tool):
+    """Tool to render the dependency tree of a Cargo, npm or pip project as a DOT graph."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "dependency_graph"
+
+    @override
+    def get_description(self) -> str:
+        return """Render the dependency tree of a project as a DOT graph, e.g. to see what a dependency upgrade could affect
+* The project type is detected from the manifest in `path`: Cargo.toml (uses `cargo metadata`), package.json (uses `npm ls --json`) or pyproject.toml (reads [project] dependencies and follows them with `pip show`)
+* npm projects need their node_modules installed, and pip projects their dependencies installed in the environment of `pip`; packages that are missing are shown but not followed
+* Set `depth` to 1 to only show direct dependencies
+* Set `include_dev` to true to also show the project's dev dependencies
+* Returns the DOT source and the number of unique packages in the graph, not counting the project itself
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Absolute path to the project root, the directory containing the manifest.",
+                required=True,
+            ),
+            ToolParameter(
+                name="depth",
+                type="integer",
+                description="Only show dependencies up to this many levels below the project. Defaults to the whole tree.",
+                required=False,
+            ),
+            ToolParameter(
+                name="include_dev",
+                type="boolean",
+                description="Also show dev dependencies. Defaults to false.",
+                required=False,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        try:
+            root = self.resolve_path(str(arguments.get("path", "")))
+            if not root.is_absolute():
+                raise ToolError(
+                    f"The path {root} is not an absolute path, it should start with `/`."
+                )
+            if not root.is_dir():
+                raise ToolError(f"The path {root} is not a directory.")
+            depth = arguments.get("depth")
+            if depth is not None and (not isinstance(depth, int) or depth < 1):
+                raise ToolError("`depth` should be a positive integer.")
+            include_dev = arguments.get("include_dev") is True
+
+            project_type = next(
+                (kind for name, kind in PROJECT_MANIFESTS.items() if (root / name).is_file()),
+                None,
+            )
+            if project_type is None:
+                raise ToolError(
+                    f"No project manifest ({', '.join(PROJECT_MANIFESTS)}) found in {root}. Point `path` at the project root."
+                )
+            command = PROJECT_TOOLS[project_type]
+            if shutil.which(command) is None:
+                raise ToolError(
+                    f"{root} is a {project_type} project, but `{command}` is not installed."
+                )
+
+            if project_type == "Cargo":
+                graph = await self._cargo_graph(root, include_dev)
+            elif project_type == "npm":
+                graph = await self._npm_graph(root, depth, include_dev)
+            else:
+                graph = await self._pip_graph(root, depth, include_dev)
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+        except (json.JSONDecodeError, KeyError) as e:
+            return ToolExecResult(
+                error=f"Could not parse the dependency tree of {arguments.get('path')}: {e}",
+                error_code=-1,
+            )
+        except (OSError, TimeoutError) as e:
+            return ToolExecResult(
+                error=f"Error reading the dependencies of {arguments.get('path')}: {e}",
+                error_code=-1,
+            )
+
+        dot, package_count = self._render(graph, depth)
+        return ToolExecResult(
+            output=maybe_truncate(
+                f"Dependency graph of the {project_type} project at {root}: {package_count} unique packages\n\n{dot}"
+            )
+        )
+
+    async def _command_output(self, command: str, root: Path, allow_failure: bool = False) -> str:
+        """Run `command` in `root` and return its stdout, without truncating it."""
+        code, stdout, stderr = await run(
+            f"cd {shlex.quote(str(root))} && {command}",
+            timeout=DEPENDENCY_COMMAND_TIMEOUT,
+            truncate_after=None,
+        )
+        if code != 0 and not (allow_failure and stdout.strip()):
+            raise ToolError(f"`{command}` failed with exit code {code}:\n{stderr.strip()}")
+        return stdout
+
+    async def _cargo_graph(self, root: Path, include_dev: bool) -> DependencyGraph:
+        metadata = json.loads(
+            await self._command_output("cargo metadata --format-version 1 --quiet", root)
+        )
+        graph = DependencyGraph()
+        for package in metadata["packages"]:
+            graph.labels[package["id"]] = f"{package['name']} {package['version']}"
+        resolve = metadata.get("resolve") or {}
+        # A virtual workspace has no root package, only members
+        graph.roots = [resolve["root"]] if resolve.get("root") else metadata["workspace_members"]
+        for node in resolve.get("nodes", []):
+            for dep in node["deps"]:
+                kinds = {kind.get("kind") for kind in dep.get("dep_kinds", [])}
+                if kinds == {"dev"} and not include_dev:
+                    continue
+                graph.add_edge(node["id"], dep["pkg"])
+        return graph
+
+    async def _npm_graph(self, root: Path, depth: int | None, include_dev: bool) -> DependencyGraph:
+        command = "npm ls --json " + ("--all" if depth is None else f"--depth={depth - 1}")
+        if not include_dev:
+            command += " --omit=dev"
+        # npm ls exits non-zero for missing or extraneous packages but still prints the tree
+        tree = json.loads(await self._command_output(command, root, allow_failure=True))
+
+        graph = DependencyGraph()
+        root_id = f"{tree.get('name', root.name)} {tree.get('version', '')}".strip()
+        graph.roots = [root_id]
+        graph.labels[root_id] = root_id
+        pending = [(root_id, tree)]
+        while pending:
+            parent_id, node = pending.pop()
+            for name, dependency in (node.get("dependencies") or {}).items():
+                if dependency.get("missing") or "version" not in dependency:
+                    dependency_id = f"{name} (missing)"
+                else:
+                    dependency_id = f"{name} {dependency['version']}"
+                graph.labels[dependency_id] = dependency_id
+                graph.add_edge(parent_id, dependency_id)
+                pending.append((dependency_id, dependency))
+        return graph
+
+    async def _pip_graph(self, root: Path, depth: int | None, include_dev: bool) -> DependencyGraph:
+        with open(root / "pyproject.toml", "rb") as f:
+            try:
+                pyproject = tomllib.load(f)
+            except tomllib.TOMLDecodeError as e:
+                raise ToolError(f"Invalid pyproject.toml: {e}") from None
+        project = pyproject.get("project")
+        if not isinstance(project, dict):
+            raise ToolError(
+                "pyproject.toml has no [project] table listing the dependencies (PEP 621)."
+            )
+        requirements: list[str] = list(project.get("dependencies", []))
+        if include_dev:
+            requirements += project.get("optional-dependencies", {}).get("dev", [])
+            # PEP 735 groups may also include other groups, written as tables
+            requirements += [
+                item
+                for item in pyproject.get("dependency-groups", {}).get("dev", [])
+                if isinstance(item, str)
+            ]
+
+        graph = DependencyGraph()
+        project_name = str(project.get("name", root.name))
+        root_id = normalize_distribution_name(project_name)
+        graph.roots = [root_id]
+        graph.labels[root_id] = f"{project_name} {project.get('version', '')}".strip()
+        for requirement in requirements:
+            match = REQUIREMENT_NAME.match(requirement)
+            if match:
+                graph.add_edge(root_id, normalize_distribution_name(match.group(1)))
+
+        # Follow the requirements one level at a time, with one `pip show` call per level
+        level = list(graph.edges.get(root_id, []))
+        level_depth = 1
+        seen = {root_id, *level}
+        while level:
+            installed = await self._pip_show(root, level)
+            next_level: list[str] = []
+            for name in level:
+                if name not in installed:
+                    graph.labels[name] = f"{name} (not installed)"
+                    continue
+                display_name, version, requires = installed[name]
+                graph.labels[name] = f"{display_name} {version}"
+                if depth is not None and level_depth >= depth:
+                    continue
+                for dependency in requires:
+                    graph.add_edge(name, dependency)
+                    if dependency not in seen:
+                        seen.add(dependency)
+                        next_level.append(dependency)
+            level = next_level
+            level_depth += 1
+        return graph
+
+    async def _pip_show(
+        self, root: Path, names: list[str]
+    ) -> dict[str, tuple[str, str, list[str]]]:
+        """Return the name, version and requirements of each installed package among `names`."""
+        # pip show prints the packages it found and exits non-zero if any are not installed
+        output = await self._command_output(
+            "pip show " + " ".join(shlex.quote(name) for name in names), root, allow_failure=True
+        )
+        packages: dict[str, tuple[str, str, list[str]]] = {}
+        for block in re.split(r"^---$", output, flags=re.MULTILINE):
+            fields: dict[str, str] = {}
+            for line in block.splitlines():
+                key, _, value = line.partition(":")
+                fields[key.strip()] = value.strip()
+            if "Name" not in fields:
+                continue
+            requires = [
+                normalize_distribution_name(name)
+                for name in fields.get("Requires", "").split(",")
+                if name.strip()
+            ]
+            packages[normalize_distribution_name(fields["Name"])] = (
+                fields["Name"],
+                fields.get("Version", ""),
+                requires,
+            )
+        return packages
+
+    def _render(self, graph: DependencyGraph, depth: int | None) -> tuple[str, int]:
+        """Render the part of `graph` within `depth` of its roots, and count its packages."""
+        distances = {root: 0 for root in graph.roots}
+        queue = deque(graph.roots)
+        lines = [f"    {dot_quote(graph.labels.get(root, root))};" for root in graph.roots]
+        while queue:
+            source = queue.popleft()
+            if depth is not None and distances[source] >= depth:
+                continue
+            source_label = dot_quote(graph.labels.get(source, source))
+            for target in graph.edges.get(source, []):
+                lines.append(f"    {source_label} -> {dot_quote(graph.labels.get(target, target))};")
+                if target not in distances:
+                    distances[target] = distances[source] + 1
+                    queue.append(target)
+
+        package_count = len(distances.keys() - set(graph.roots))
+        return "\n".join(["digraph dependencies {", *lines, "}"]), package_count
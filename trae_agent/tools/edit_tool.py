@@ -9,10 +9,19 @@
 #
 # This modified file is released under the same license.
 
+import difflib
+import shlex
 from pathlib import Path
 from typing import override
 
-from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .base import (
+    DRY_RUN_NOTICE,
+    Tool,
+    ToolCallArguments,
+    ToolError,
+    ToolExecResult,
+    ToolParameter,
+)
 from .run import maybe_truncate, run
 
 EditToolSubCommands = [
@@ -22,6 +31,7 @@ EditToolSubCommands = [
     "insert",
 ]
 SNIPPET_LINES: int = 4
+DIRECTORY_PAGE_SIZE: int = 200
 
 
 class TextEditorTool(Tool):
@@ -29,6 +39,8 @@ class TextEditorTool(Tool):
 
     def __init__(self, model_provider: str | None = None) -> None:
         super().__init__(model_provider)
+        # Last known content of each file the agent has viewed or written
+        self._read_snapshots: dict[Path, str] = {}
 
     @override
     def get_model_provider(self) -> str | None:
@@ -43,8 +55,10 @@ class TextEditorTool(Tool):
         return """Custom editing tool for viewing, creating and editing files
 * State is persistent across command calls and discussions with the user
 * If `path` is a file, `view` displays the result of applying `cat -n`. If `path` is a directory, `view` lists non-hidden files and directories up to 2 levels deep
+* Directory listings are paginated. If more entries remain, the output ends with a `page_token` to pass to the next `view` call. The token is an offset into the listing, so it is only valid while the directory does not change; list it again from the start after creating or deleting entries
 * The `create` command cannot be used if the specified `path` already exists as a file !!! If you know that the `path` already exists, please remove it first and then perform the `create` operation!
-* If a `command` generates a long output, it will be truncated and marked with `<response clipped>`
+* If a `command` generates a long output, its middle is replaced with a `[... N lines omitted ...]` marker. Use `view_range` to see the omitted lines
+* If a file you viewed was changed by someone else before you edit it, the edit is rejected and the external change is shown. View the file again, or pass `force: true` to edit anyway
 
 Notes for using the `str_replace` command:
 * The `old_str` parameter should match EXACTLY one or more consecutive lines from the original file. Be mindful of whitespaces!
@@ -95,11 +109,38 @@ Notes for using the `str_replace` command:
                 description="Optional parameter of `view` command when `path` points to a file. If none is given, the full file is shown. If provided, the file will be shown in the indicated line number range, e.g. [11, 12] will show lines 11 and 12. Indexing at 1 to start. Setting `[start_line, -1]` shows all lines from `start_line` to the end of the file.",
                 items={"type": "integer"},
             ),
+            ToolParameter(
+                name="page_size",
+                type="integer",
+                description=f"Optional parameter of `view` command when `path` points to a directory. Maximum number of entries to list, defaults to {DIRECTORY_PAGE_SIZE}.",
+            ),
+            ToolParameter(
+                name="page_token",
+                type="string",
+                description="Optional parameter of `view` command when `path` points to a directory. The `page_token` returned by a previous listing of the same directory, to continue from where it stopped. Tokens are offsets into the listing and become invalid if the directory changes.",
+            ),
+            ToolParameter(
+                name="force",
+                type="boolean",
+                description="Optional parameter of `str_replace` and `insert` commands. Apply the edit even if the file was modified by someone else since you last viewed it.",
+            ),
         ]
 
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return arguments.get("command") == "view"
+
     @override
     async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
         """Execute the str_replace_editor tool."""
+        return await self._run(arguments, dry_run=False)
+
+    @override
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report the diff an edit would make without writing the file."""
+        return await self._run(arguments, dry_run=True)
+
+    async def _run(self, arguments: ToolCallArguments, dry_run: bool) -> ToolExecResult:
         command = str(arguments["command"]) if "command" in arguments else None
         if command is None:
             return ToolExecResult(
@@ -111,18 +152,18 @@ Notes for using the `str_replace` command:
             return ToolExecResult(
                 error=f"No path provided for the {self.get_name()} tool", error_code=-1
             )
-        _path = Path(path)
+        _path = self.resolve_path(path)
         try:
             self.validate_path(command, _path)
             match command:
                 case "view":
                     return await self._view_handler(arguments, _path)
                 case "create":
-                    return self._create_handler(arguments, _path)
+                    return self._create_handler(arguments, _path, dry_run)
                 case "str_replace":
-                    return self._str_replace_handler(arguments, _path)
+                    return self._str_replace_handler(arguments, _path, dry_run)
                 case "insert":
-                    return self._insert_handler(arguments, _path)
+                    return self._insert_handler(arguments, _path, dry_run)
                 case _:
                     return ToolExecResult(
                         error=f"Unrecognized command {command}. The allowed commands for the {self.name} tool are: {', '.join(EditToolSubCommands)}",
@@ -151,7 +192,13 @@ Notes for using the `str_replace` command:
                 f"The path {path} is a directory and only the `view` command can be used on directories"
             )
 
-    async def _view(self, path: Path, view_range: list[int] | None = None) -> ToolExecResult:
+    async def _view(
+        self,
+        path: Path,
+        view_range: list[int] | None = None,
+        page_size: int | None = None,
+        page_token: str | None = None,
+    ) -> ToolExecResult:
         """Implement the view command"""
         if path.is_dir():
             if view_range:
@@ -159,12 +206,24 @@ Notes for using the `str_replace` command:
                     "The `view_range` parameter is not allowed when `path` points to a directory."
                 )
 
-            return_code, stdout, stderr = await run(rf"find {path} -maxdepth 2 -not -path '*/\.*'")
-            if not stderr:
-                stdout = f"Here's the files and directories up to 2 levels deep in {path}, excluding hidden items:\n{stdout}\n"
-            return ToolExecResult(error_code=return_code, output=stdout, error=stderr)
+            # The listing is paginated instead of truncated, so it must be read in full
+            return_code, stdout, stderr = await run(
+                rf"find {shlex.quote(str(path))} -maxdepth 2 -not -path '*/\.*'",
+                truncate_after=None,
+            )
+            # Errors such as unreadable subdirectories are reported next to the partial listing
+            if stdout:
+                stdout = f"Here's the files and directories up to 2 levels deep in {path}, excluding hidden items:\n{self._paginate_listing(stdout, page_size, page_token)}\n"
+            return ToolExecResult(
+                error_code=return_code, output=stdout, error=maybe_truncate(stderr)
+            )
+        if page_size is not None or page_token is not None:
+            raise ToolError(
+                "The `page_size` and `page_token` parameters are only allowed when `path` points to a directory."
+            )
 
         file_content = self.read_file(path)
+        self._remember_content(path, file_content)
         init_line = 1
         if view_range:
             if len(view_range) != 2 or not all(isinstance(i, int) for i in view_range):  # pyright: ignore[reportUnnecessaryIsInstance]
@@ -194,10 +253,47 @@ Notes for using the `str_replace` command:
             output=self._make_output(file_content, str(path), init_line=init_line)
         )
 
-    def str_replace(self, path: Path, old_str: str, new_str: str | None) -> ToolExecResult:
+    def _paginate_listing(
+        self, listing: str, page_size: int | None, page_token: str | None
+    ) -> str:
+        """Return one page of a directory listing, with a total count and the next page token."""
+        entries = sorted(line for line in listing.split("\n") if line)
+        page_size = DIRECTORY_PAGE_SIZE if page_size is None else page_size
+        if page_size < 1:
+            raise ToolError(f"Invalid `page_size` {page_size}. It should be a positive integer.")
+        # The token is the offset of the next entry; the listing is sorted, so it is stable
+        # across calls as long as the directory does not change
+        start = 0
+        if page_token is not None:
+            if not page_token.isdecimal() or int(page_token) >= max(len(entries), 1):
+                raise ToolError(
+                    f"Invalid `page_token` {page_token!r}. Use the token returned by the previous listing."
+                )
+            start = int(page_token)
+
+        end = min(start + page_size, len(entries))
+        page = "\n".join(entries[start:end])
+        if start == 0 and end == len(entries):
+            return page
+        page += f"\n[Showing entries {start + 1}-{end} of {len(entries)}."
+        if end < len(entries):
+            page += f' To see more, call `view` again with page_token "{end}".'
+        return page + "]"
+
+    def str_replace(
+        self,
+        path: Path,
+        old_str: str,
+        new_str: str | None,
+        force: bool = False,
+        dry_run: bool = False,
+    ) -> ToolExecResult:
         """Implement the str_replace command, which replaces old_str with new_str in the file content"""
         # Read the file content
-        file_content = self.read_file(path).expandtabs()
+        original_content = self.read_file(path)
+        if not force:
+            self._check_unmodified_since_read(path, original_content)
+        file_content = original_content.expandtabs()
         old_str = old_str.expandtabs()
         new_str = new_str.expandtabs() if new_str is not None else ""
 
@@ -217,8 +313,13 @@ Notes for using the `str_replace` command:
         # Replace old_str with new_str
         new_file_content = file_content.replace(old_str, new_str)
 
+        if dry_run:
+            # Diffed against the file as it is, so tabs the write would expand show up too
+            return self._dry_run_result(path, original_content, new_file_content)
+
         # Write the new content to the file
         self.write_file(path, new_file_content)
+        self._remember_content(path, new_file_content)
 
         # Create a snippet of the edited section
         replacement_line = file_content.split(old_str)[0].count("\n")
@@ -235,9 +336,19 @@ Notes for using the `str_replace` command:
             output=success_msg,
         )
 
-    def _insert(self, path: Path, insert_line: int, new_str: str) -> ToolExecResult:
+    def _insert(
+        self,
+        path: Path,
+        insert_line: int,
+        new_str: str,
+        force: bool = False,
+        dry_run: bool = False,
+    ) -> ToolExecResult:
         """Implement the insert command, which inserts new_str at the specified line in the file content."""
-        file_text = self.read_file(path).expandtabs()
+        original_text = self.read_file(path)
+        if not force:
+            self._check_unmodified_since_read(path, original_text)
+        file_text = original_text.expandtabs()
         new_str = new_str.expandtabs()
         file_text_lines = file_text.split("\n")
         n_lines_file = len(file_text_lines)
@@ -260,7 +371,11 @@ Notes for using the `str_replace` command:
         new_file_text = "\n".join(new_file_text_lines)
         snippet = "\n".join(snippet_lines)
 
+        if dry_run:
+            return self._dry_run_result(path, original_text, new_file_text)
+
         self.write_file(path, new_file_text)
+        self._remember_content(path, new_file_text)
 
         success_msg = f"The file {path} has been edited. "
         success_msg += self._make_output(
@@ -289,6 +404,45 @@ Notes for using the `str_replace` command:
         except Exception as e:
             raise ToolError(f"Ran into {e} while trying to write to {path}") from None
 
+    def _remember_content(self, path: Path, content: str) -> None:
+        """Record what the agent last saw of `path`, so later edits can detect external changes."""
+        # Keyed on the resolved path so `/repo/../repo/a.py` and `/repo/a.py` share one snapshot
+        self._read_snapshots[path.resolve()] = content
+
+    def _check_unmodified_since_read(self, path: Path, current_content: str) -> None:
+        """Raise a ToolError if `path` was changed by someone else since the agent last saw it."""
+        seen_content = self._read_snapshots.get(path.resolve())
+        if seen_content is None or current_content == seen_content:
+            return
+
+        diff = "\n".join(
+            difflib.unified_diff(
+                seen_content.split("\n"),
+                current_content.split("\n"),
+                fromfile=f"{path} (last read)",
+                tofile=f"{path} (current)",
+                lineterm="",
+            )
+        )
+        raise ToolError(
+            f"File {path} has been modified since it was last read, no changes were applied. The external change was:\n{maybe_truncate(diff)}\nView the file again before editing it, or pass `force: true` to overwrite the external change."
+        )
+
+    def _dry_run_result(self, path: Path, old_content: str, new_content: str) -> ToolExecResult:
+        """Describe the change a write to `path` would make, without applying it."""
+        diff = "\n".join(
+            difflib.unified_diff(
+                old_content.split("\n") if old_content else [],
+                new_content.split("\n"),
+                fromfile=str(path) if old_content else "/dev/null",
+                tofile=str(path),
+                lineterm="",
+            )
+        )
+        return ToolExecResult(
+            output=f"{DRY_RUN_NOTICE} The following change to {path} would be made:\n{diff}\n"
+        )
+
     def _make_output(
         self,
         file_content: str,
@@ -296,8 +450,11 @@ Notes for using the `str_replace` command:
         init_line: int = 1,
         expand_tabs: bool = True,
     ):
-        """Generate output for the CLI based on the content of a file."""
-        file_content = maybe_truncate(file_content)
+        """Generate output for the CLI based on the content of a file.
+
+        Long output is not cut here: the tool executor keeps its head and tail within the
+        max_tool_output budget.
+        """
         if expand_tabs:
             file_content = file_content.expandtabs()
         file_content = "\n".join(
@@ -308,28 +465,47 @@ Notes for using the `str_replace` command:
         )
 
     async def _view_handler(self, arguments: ToolCallArguments, _path: Path) -> ToolExecResult:
+        page_size = arguments.get("page_size", None)
+        if not (page_size is None or isinstance(page_size, int)):
+            return ToolExecResult(
+                error="Parameter `page_size` should be an integer.",
+                error_code=-1,
+            )
+        page_token = arguments.get("page_token", None)
+        if not (page_token is None or isinstance(page_token, str)):
+            return ToolExecResult(
+                error="Parameter `page_token` should be a string.",
+                error_code=-1,
+            )
         view_range = arguments.get("view_range", None)
         if view_range is None:
-            return await self._view(_path, None)
+            return await self._view(_path, None, page_size, page_token)
         if not (isinstance(view_range, list) and all(isinstance(i, int) for i in view_range)):
             return ToolExecResult(
                 error="Parameter `view_range` should be a list of integers.",
                 error_code=-1,
             )
         view_range_int: list[int] = [i for i in view_range if isinstance(i, int)]
-        return await self._view(_path, view_range_int)
+        return await self._view(_path, view_range_int, page_size, page_token)
 
-    def _create_handler(self, arguments: ToolCallArguments, _path: Path) -> ToolExecResult:
+    def _create_handler(
+        self, arguments: ToolCallArguments, _path: Path, dry_run: bool
+    ) -> ToolExecResult:
         file_text = arguments.get("file_text", None)
         if not isinstance(file_text, str):
             return ToolExecResult(
                 error="Parameter `file_text` is required and must be a string for command: create",
                 error_code=-1,
             )
+        if dry_run:
+            return self._dry_run_result(_path, "", file_text)
         self.write_file(_path, file_text)
+        self._remember_content(_path, file_text)
         return ToolExecResult(output=f"File created successfully at: {_path}")
 
-    def _str_replace_handler(self, arguments: ToolCallArguments, _path: Path) -> ToolExecResult:
+    def _str_replace_handler(
+        self, arguments: ToolCallArguments, _path: Path, dry_run: bool
+    ) -> ToolExecResult:
         old_str = arguments.get("old_str") if "old_str" in arguments else None
         if not isinstance(old_str, str):
             return ToolExecResult(
@@ -342,9 +518,13 @@ Notes for using the `str_replace` command:
                 error="Parameter `new_str` should be a string or null for command: str_replace",
                 error_code=-1,
             )
-        return self.str_replace(_path, old_str, new_str)
+        return self.str_replace(
+            _path, old_str, new_str, force=arguments.get("force") is True, dry_run=dry_run
+        )
 
-    def _insert_handler(self, arguments: ToolCallArguments, _path: Path) -> ToolExecResult:
+    def _insert_handler(
+        self, arguments: ToolCallArguments, _path: Path, dry_run: bool
+    ) -> ToolExecResult:
         insert_line = arguments.get("insert_line") if "insert_line" in arguments else None
         if not isinstance(insert_line, int):
             return ToolExecResult(
@@ -357,4 +537,10 @@ Notes for using the `str_replace` command:
                 error="Parameter `new_str` is required for command: insert",
                 error_code=-1,
             )
-        return self._insert(_path, insert_line, new_str_to_insert)
+        return self._insert(
+            _path,
+            insert_line,
+            new_str_to_insert,
+            force=arguments.get("force") is True,
+            dry_run=dry_run,
+        )
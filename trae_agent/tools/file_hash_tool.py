@@ -0,0 +1,130 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool for computing file digests, e.g. to detect concurrent modification."""
+
+import hashlib
+import os
+from pathlib import Path
+from typing import override
+
+from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .run import maybe_truncate
+
+# blake2b is the fast option; blake3 is not in the standard library
+FileHashAlgorithms = ["sha256", "md5", "blake2b"]
+CHUNK_SIZE: int = 1024 * 1024
+
+
+class FileHashTool(Tool):
+    """Tool to compute the hash of a file or of every file in a directory."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "file_hash"
+
+    @override
+    def get_description(self) -> str:
+        return """Compute the hex digest and size of a file
+* Use it to check whether a file changed between two points in time, e.g. before writing to a file you viewed earlier
+* Supported algorithms: sha256 (default), md5, blake2b (fastest for large files)
+* If `path` is a directory, set `manifest` to true to hash every non-hidden file below it; entries are listed by relative path
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Absolute path to the file or directory to hash.",
+                required=True,
+            ),
+            ToolParameter(
+                name="algorithm",
+                type="string",
+                description="Hash algorithm to use. Defaults to sha256.",
+                enum=FileHashAlgorithms,
+                required=False,
+            ),
+            ToolParameter(
+                name="manifest",
+                type="boolean",
+                description="Required to hash a directory. Returns one line per file.",
+                required=False,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        try:
+            path = self.resolve_path(str(arguments.get("path", "")))
+            if not path.is_absolute():
+                raise ToolError(
+                    f"The path {path} is not an absolute path, it should start with `/`."
+                )
+            if not path.exists():
+                raise ToolError(f"The path {path} does not exist.")
+
+            algorithm = str(arguments.get("algorithm") or "sha256").lower()
+            if algorithm not in FileHashAlgorithms:
+                raise ToolError(
+                    f"Unsupported algorithm: {algorithm}. Supported algorithms: {', '.join(FileHashAlgorithms)}"
+                )
+
+            if path.is_dir():
+                if arguments.get("manifest") is not True:
+                    raise ToolError(
+                        f"The path {path} is a directory. Set `manifest` to true to hash every file in it."
+                    )
+                return ToolExecResult(output=self._hash_directory(path, algorithm))
+            # Reading a FIFO or a device such as /dev/zero would block or never end
+            if not path.is_file():
+                raise ToolError(f"The path {path} is not a regular file or directory.")
+
+            digest, size = self._hash_file(path, algorithm)
+            return ToolExecResult(output=f"{algorithm} {digest}  {path} ({size} bytes)")
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+        except OSError as e:
+            return ToolExecResult(
+                error=f"Error hashing {arguments.get('path')}: {e}", error_code=-1
+            )
+
+    def _hash_file(self, path: Path, algorithm: str) -> tuple[str, int]:
+        """Stream the file through the hasher so large files are never fully loaded."""
+        hasher = hashlib.new(algorithm)
+        size = 0
+        with open(path, "rb") as f:
+            while chunk := f.read(CHUNK_SIZE):
+                hasher.update(chunk)
+                size += len(chunk)
+        return hasher.hexdigest(), size
+
+    def _hash_directory(self, root: Path, algorithm: str) -> str:
+        files: list[Path] = []
+        for dirpath, dirnames, filenames in os.walk(root):
+            # Prune hidden directories in place so trees like .git are never walked
+            dirnames[:] = [name for name in dirnames if not name.startswith(".")]
+            files.extend(Path(dirpath, name) for name in filenames if not name.startswith("."))
+
+        lines: list[str] = []
+        for file_path in sorted(files):
+            if not file_path.is_file():
+                continue
+            digest, size = self._hash_file(file_path, algorithm)
+            lines.append(f"{digest}  {file_path.relative_to(root)} ({size} bytes)")
+
+        header = f"{algorithm} manifest of {root} ({len(lines)} files):"
+        return maybe_truncate("\n".join([header, *lines]))
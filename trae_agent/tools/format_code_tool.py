@@ -0,0 +1,228 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool for formatting source code with the formatter of its language."""
+
+import asyncio
+import contextlib
+import difflib
+import shutil
+from pathlib import Path
+from typing import override
+
+from .base import (
+    DRY_RUN_NOTICE,
+    Tool,
+    ToolCallArguments,
+    ToolError,
+    ToolExecResult,
+    ToolParameter,
+)
+from .write_file_tool import write_atomic
+
+FORMAT_TIMEOUT: float = 60.0  # seconds
+
+# Formatter of each supported language
+FORMATTERS: dict[str, str] = {
+    "rust": "rustfmt",
+    "javascript": "prettier",
+    "typescript": "prettier",
+    "json": "prettier",
+    "css": "prettier",
+    "python": "black",
+    "go": "gofmt",
+}
+FormatLanguages = list(FORMATTERS)
+LANGUAGE_EXTENSIONS: dict[str, str] = {
+    ".rs": "rust",
+    ".js": "javascript",
+    ".jsx": "javascript",
+    ".mjs": "javascript",
+    ".cjs": "javascript",
+    ".ts": "typescript",
+    ".tsx": "typescript",
+    ".json": "json",
+    ".css": "css",
+    ".scss": "css",
+    ".less": "css",
+    ".py": "python",
+    ".pyi": "python",
+    ".go": "go",
+}
+# prettier picks its parser from a file name, which `content` does not have
+PRETTIER_STDIN_NAMES: dict[str, str] = {
+    "javascript": "stdin.js",
+    "typescript": "stdin.ts",
+    "json": "stdin.json",
+    "css": "stdin.css",
+}
+
+
+class FormatCodeTool(Tool):
+    """Tool to format a file in place, or a snippet of code, with rustfmt, prettier, black or gofmt."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "format_code"
+
+    @override
+    def get_description(self) -> str:
+        return """Format code with the standard formatter of its language: rustfmt for Rust, prettier for JavaScript, TypeScript, JSON and CSS, black for Python, gofmt for Go
+* Give `path` to format a file in place. The language is detected from the file extension unless `language` is given. The file is replaced atomically, so it is never left half written
+* Give `content` and `language` to get the formatted code back without writing anything
+* Formatting fails if the code has syntax errors, or if the formatter is not installed
+* The formatters pick up project configuration such as rustfmt.toml, .prettierrc or pyproject.toml when formatting a file
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Path to the file to format in place. Leave out when giving `content`.",
+                required=False,
+            ),
+            ToolParameter(
+                name="content",
+                type="string",
+                description="Code to format and return. Leave out when giving `path`.",
+                required=False,
+            ),
+            ToolParameter(
+                name="language",
+                type="string",
+                description="Language of the code. Required with `content`; detected from the extension of `path` if omitted.",
+                enum=FormatLanguages,
+                required=False,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return arguments.get("path") is None
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        return await self._run(arguments, dry_run=False)
+
+    @override
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report the diff formatting would make without writing the file."""
+        return await self._run(arguments, dry_run=True)
+
+    async def _run(self, arguments: ToolCallArguments, dry_run: bool) -> ToolExecResult:
+        try:
+            path_argument = arguments.get("path")
+            content = arguments.get("content")
+            if (path_argument is None) == (content is None):
+                raise ToolError("Give exactly one of `path` and `content`.")
+            language = arguments.get("language")
+            if language is not None and language not in FORMATTERS:
+                raise ToolError(
+                    f"Unsupported language: {language}. Supported languages: {', '.join(FormatLanguages)}"
+                )
+
+            if content is not None:
+                if language is None:
+                    raise ToolError("Parameter `language` is required with `content`.")
+                return ToolExecResult(
+                    output=await self._format(str(content), str(language), path=None)
+                )
+
+            path = self.resolve_path(str(path_argument))
+            if not path.is_absolute():
+                raise ToolError(
+                    f"The path {path} is not an absolute path, it should start with `/`."
+                )
+            if not path.is_file():
+                raise ToolError(f"The path {path} does not exist or is not a file.")
+            if language is None:
+                language = LANGUAGE_EXTENSIONS.get(path.suffix.lower())
+                if language is None:
+                    raise ToolError(
+                        f"Cannot tell the language of {path} from its extension. Set `language` to one of: {', '.join(FormatLanguages)}"
+                    )
+            try:
+                original = path.read_text(encoding="utf-8")
+            except UnicodeDecodeError:
+                raise ToolError(f"{path} is not a UTF-8 text file.") from None
+
+            formatted = await self._format(original, str(language), path)
+            if formatted == original:
+                return ToolExecResult(output=f"{path} is already formatted.")
+            if dry_run:
+                diff = "\n".join(
+                    difflib.unified_diff(
+                        original.split("\n"),
+                        formatted.split("\n"),
+                        fromfile=str(path),
+                        tofile=str(path),
+                        lineterm="",
+                    )
+                )
+                return ToolExecResult(
+                    output=f"{DRY_RUN_NOTICE} Formatting {path} would make the following change:\n{diff}\n"
+                )
+            write_atomic(path, formatted.encode("utf-8"))
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+        except OSError as e:
+            return ToolExecResult(
+                error=f"Error formatting {arguments.get('path')}: {e}", error_code=-1
+            )
+        return ToolExecResult(output=f"Formatted {path} with {FORMATTERS[str(language)]}.")
+
+    async def _format(self, code: str, language: str, path: Path | None) -> str:
+        """Pipe `code` through the formatter of `language` and return its output.
+
+        `path` is the file the code comes from, if any, so the formatter finds its configuration.
+        """
+        formatter = FORMATTERS[language]
+        executable = shutil.which(formatter)
+        if executable is None:
+            missing = sorted({name for name in FORMATTERS.values() if shutil.which(name) is None})
+            raise ToolError(
+                f"{language} code is formatted with {formatter}, which is not installed. Formatters not found on PATH: {', '.join(missing)}"
+            )
+
+        match formatter:
+            case "rustfmt":
+                argv = [executable, "--edition", "2021"]
+            case "prettier":
+                stdin_name = str(path) if path is not None else PRETTIER_STDIN_NAMES[language]
+                argv = [executable, "--stdin-filepath", stdin_name]
+            case "black":
+                argv = [executable, "--quiet", "-"]
+                if path is not None:
+                    argv[2:2] = ["--stdin-filename", str(path)]
+            case _:
+                argv = [executable]
+
+        process = await asyncio.create_subprocess_exec(
+            *argv,
+            stdin=asyncio.subprocess.PIPE,
+            stdout=asyncio.subprocess.PIPE,
+            stderr=asyncio.subprocess.PIPE,
+            cwd=path.parent if path is not None else None,
+        )
+        try:
+            stdout, stderr = await asyncio.wait_for(
+                process.communicate(code.encode("utf-8")), timeout=FORMAT_TIMEOUT
+            )
+        except asyncio.TimeoutError:
+            with contextlib.suppress(ProcessLookupError):
+                process.kill()
+            raise ToolError(f"{formatter} timed out after {FORMAT_TIMEOUT:g} seconds.") from None
+        if process.returncode != 0:
+            raise ToolError(
+                f"{formatter} failed with exit code {process.returncode}:\n{stderr.decode(errors='replace').strip()}"
+            )
+        return stdout.decode("utf-8")
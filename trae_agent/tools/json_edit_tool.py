@@ -3,6 +3,7 @@
 
 """JSON editing tool for structured JSON file modifications."""
 
+import difflib
 import json
 from pathlib import Path
 from typing import override
@@ -11,7 +12,14 @@ from jsonpath_ng import Fields, Index
 from jsonpath_ng import parse as jsonpath_parse
 from jsonpath_ng.exceptions import JSONPathError
 
-from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .base import (
+    DRY_RUN_NOTICE,
+    Tool,
+    ToolCallArguments,
+    ToolError,
+    ToolExecResult,
+    ToolParameter,
+)
 
 
 class JSONEditTool(Tool):
@@ -89,9 +97,21 @@ JSONPath syntax supported:
             ),
         ]
 
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return str(arguments.get("operation", "")).lower() == "view"
+
     @override
     async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
         """Execute the JSON edit operation."""
+        return await self._run(arguments, dry_run=False)
+
+    @override
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report the diff an edit would make without writing the file."""
+        return await self._run(arguments, dry_run=True)
+
+    async def _run(self, arguments: ToolCallArguments, dry_run: bool) -> ToolExecResult:
         try:
             operation = str(arguments.get("operation", "")).lower()
             if not operation:
@@ -101,7 +121,7 @@ JSONPath syntax supported:
             if not file_path_str:
                 return ToolExecResult(error="file_path parameter is required", error_code=-1)
 
-            file_path = Path(file_path_str)
+            file_path = self.resolve_path(file_path_str)
             if not file_path.is_absolute():
                 return ToolExecResult(
                     error=f"File path must be absolute: {file_path}", error_code=-1
@@ -136,15 +156,17 @@ JSONPath syntax supported:
                     )
                 if operation == "set":
                     return await self._set_json_value(
-                        file_path, json_path_arg, value, pretty_print_arg
+                        file_path, json_path_arg, value, pretty_print_arg, dry_run
                     )
                 else:  # operation == "add"
                     return await self._add_json_value(
-                        file_path, json_path_arg, value, pretty_print_arg
+                        file_path, json_path_arg, value, pretty_print_arg, dry_run
                     )
 
             if operation == "remove":
-                return await self._remove_json_value(file_path, json_path_arg, pretty_print_arg)
+                return await self._remove_json_value(
+                    file_path, json_path_arg, pretty_print_arg, dry_run
+                )
 
             return ToolExecResult(
                 error=f"Unknown operation: {operation}. Supported operations: view, set, add, remove",
@@ -183,6 +205,37 @@ JSONPath syntax supported:
         except Exception as e:
             raise ToolError(f"Error writing to file {file_path}: {str(e)}") from e
 
+    async def _save_or_preview(
+        self,
+        file_path: Path,
+        data: dict | list,
+        pretty_print: bool,
+        success_message: str,
+        dry_run: bool,
+    ) -> ToolExecResult:
+        """Save JSON data to file, or in dry-run mode report the diff the save would produce."""
+        if not dry_run:
+            await self._save_json_file(file_path, data, pretty_print)
+            return ToolExecResult(output=success_message)
+
+        old_content = file_path.read_text(encoding="utf-8")
+        if pretty_print:
+            new_content = json.dumps(data, indent=2, ensure_ascii=False)
+        else:
+            new_content = json.dumps(data, ensure_ascii=False)
+        diff = "\n".join(
+            difflib.unified_diff(
+                old_content.splitlines(),
+                new_content.splitlines(),
+                fromfile=str(file_path),
+                tofile=str(file_path),
+                lineterm="",
+            )
+        )
+        return ToolExecResult(
+            output=f"{DRY_RUN_NOTICE} The following change to {file_path} would be made:\n{diff}\n"
+        )
+
     def _parse_jsonpath(self, json_path_str: str):
         """Parse JSONPath expression with error handling."""
         try:
@@ -224,7 +277,7 @@ JSONPath syntax supported:
             return ToolExecResult(output=f"JSON content of {file_path}:\n{output}")
 
     async def _set_json_value(
-        self, file_path: Path, json_path_str: str, value, pretty_print: bool
+        self, file_path: Path, json_path_str: str, value, pretty_print: bool, dry_run: bool
     ) -> ToolExecResult:
         """Set value at specified JSONPath."""
         data = await self._load_json_file(file_path)
@@ -237,15 +290,18 @@ JSONPath syntax supported:
             )
 
         updated_data = jsonpath_expr.update(data, value)
-        await self._save_json_file(file_path, updated_data, pretty_print)
 
         match_count = len(matches)
-        return ToolExecResult(
-            output=f"Successfully updated {match_count} location(s) at JSONPath '{json_path_str}' with value: {json.dumps(value)}"
+        return await self._save_or_preview(
+            file_path,
+            updated_data,
+            pretty_print,
+            f"Successfully updated {match_count} location(s) at JSONPath '{json_path_str}' with value: {json.dumps(value)}",
+            dry_run,
         )
 
     async def _add_json_value(
-        self, file_path: Path, json_path_str: str, value, pretty_print: bool
+        self, file_path: Path, json_path_str: str, value, pretty_print: bool, dry_run: bool
     ) -> ToolExecResult:
         """Add value at specified JSONPath."""
         data = await self._load_json_file(file_path)
@@ -282,11 +338,16 @@ JSONPath syntax supported:
                     error_code=-1,
                 )
 
-        await self._save_json_file(file_path, data, pretty_print)
-        return ToolExecResult(output=f"Successfully added value at JSONPath '{json_path_str}'")
+        return await self._save_or_preview(
+            file_path,
+            data,
+            pretty_print,
+            f"Successfully added value at JSONPath '{json_path_str}'",
+            dry_run,
+        )
 
     async def _remove_json_value(
-        self, file_path: Path, json_path_str: str, pretty_print: bool
+        self, file_path: Path, json_path_str: str, pretty_print: bool, dry_run: bool
     ) -> ToolExecResult:
         """Remove value at specified JSONPath."""
         data = await self._load_json_file(file_path)
@@ -323,7 +384,10 @@ JSONPath syntax supported:
                 except (KeyError, IndexError):
                     pass
 
-        await self._save_json_file(file_path, data, pretty_print)
-        return ToolExecResult(
-            output=f"Successfully removed {match_count} element(s) at JSONPath '{json_path_str}'"
+        return await self._save_or_preview(
+            file_path,
+            data,
+            pretty_print,
+            f"Successfully removed {match_count} element(s) at JSONPath '{json_path_str}'",
+            dry_run,
         )
@@ -0,0 +1,183 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool for reading text files in any of the common encodings."""
+
+import codecs
+import itertools
+from pathlib import Path
+from typing import override
+
+from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .run import maybe_truncate
+
+# Encodings read_file detects and write_file can produce
+FileEncodings = ["utf-8", "utf-16-le", "utf-16-be", "latin-1"]
+# Bytes looked at to detect the encoding, the rest of the file is only decoded
+SAMPLE_SIZE: int = 64 * 1024
+BYTE_ORDER_MARKS: list[tuple[bytes, str]] = [
+    (codecs.BOM_UTF8, "utf-8"),
+    (codecs.BOM_UTF16_LE, "utf-16-le"),
+    (codecs.BOM_UTF16_BE, "utf-16-be"),
+]
+
+
+def detect_encoding(sample: bytes) -> str:
+    """Guess the encoding of a file from its first bytes.
+
+    A byte order mark wins. Otherwise NUL bytes in every other position mean UTF-16, text that
+    decodes as UTF-8 is UTF-8, and anything else is read as Latin-1, which decodes every byte.
+    Raises a ToolError for NUL bytes that do not look like UTF-16, i.e. binary files.
+    """
+    for bom, encoding in BYTE_ORDER_MARKS:
+        if sample.startswith(bom):
+            return encoding
+
+    if b"\x00" in sample:
+        # ASCII characters in UTF-16 have a zero high byte, which comes second in little endian
+        even, odd = sample[0::2], sample[1::2]
+        if odd.count(0) > len(odd) // 2 and even.count(0) == 0:
+            return "utf-16-le"
+        if even.count(0) > len(even) // 2 and odd.count(0) == 0:
+            return "utf-16-be"
+        raise ToolError("The file contains NUL bytes and looks like a binary file.")
+
+    try:
+        # Not final, so a sample that ends inside a multi-byte character still counts as UTF-8
+        _ = codecs.getincrementaldecoder("utf-8")().decode(sample, final=False)
+        return "utf-8"
+    except UnicodeDecodeError:
+        return "latin-1"
+
+
+class ReadFileTool(Tool):
+    """Tool to read a text file, or a range of its lines, whatever its encoding."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "read_file"
+
+    @override
+    def get_description(self) -> str:
+        return """Read a text file, detecting its encoding
+* The encoding is detected from the byte order mark or the content: UTF-8, UTF-16 LE/BE or Latin-1. Pass `encoding` to override it
+* Use `range` to read only some lines of a large file, e.g. [100, 150]. Lines before the range are skipped without keeping the whole file in memory
+* The output starts with the detected encoding, pass it to `write_file` to keep the file's encoding
+* Lines are returned as they are, without line numbers
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Absolute path to the file to read.",
+                required=True,
+            ),
+            ToolParameter(
+                name="range",
+                type="array",
+                description="Lines to read as [start_line, end_line], both inclusive and indexed from 1. Use -1 as end_line to read to the end of the file. Reads the whole file if omitted.",
+                items={"type": "integer"},
+                required=False,
+            ),
+            ToolParameter(
+                name="encoding",
+                type="string",
+                description="Encoding of the file. Detected if omitted.",
+                enum=FileEncodings,
+                required=False,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        try:
+            path = self.resolve_path(str(arguments.get("path", "")))
+            if not path.is_absolute():
+                raise ToolError(
+                    f"The path {path} is not an absolute path, it should start with `/`."
+                )
+            if not path.is_file():
+                raise ToolError(f"The path {path} does not exist or is not a file.")
+
+            line_range = self._parse_range(arguments.get("range"))
+            encoding = arguments.get("encoding")
+            if encoding is not None and encoding not in FileEncodings:
+                raise ToolError(
+                    f"Unsupported encoding: {encoding}. Supported encodings: {', '.join(FileEncodings)}"
+                )
+            if encoding is None:
+                with open(path, "rb") as f:
+                    encoding = detect_encoding(f.read(SAMPLE_SIZE))
+                try:
+                    content = self._read(path, encoding, line_range)
+                except UnicodeDecodeError:
+                    # The sample was UTF-8 but a later part of the file is not
+                    encoding = "latin-1"
+                    content = self._read(path, encoding, line_range)
+            else:
+                try:
+                    content = self._read(path, str(encoding), line_range)
+                except UnicodeDecodeError as e:
+                    raise ToolError(
+                        f"The file is not valid {encoding}: {e.reason} at byte {e.start}. Omit `encoding` to detect it."
+                    ) from None
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+        except OSError as e:
+            return ToolExecResult(
+                error=f"Error reading {arguments.get('path')}: {e}", error_code=-1
+            )
+
+        if line_range is None:
+            header = f"Here's the content of {path} (encoding: {encoding}):"
+        else:
+            end = "the end" if line_range[1] == -1 else str(line_range[1])
+            header = f"Here are lines {line_range[0]} to {end} of {path} (encoding: {encoding}):"
+        return ToolExecResult(output=maybe_truncate(f"{header}\n{content}"))
+
+    def _parse_range(self, line_range: object) -> tuple[int, int] | None:
+        if line_range is None:
+            return None
+        if not isinstance(line_range, list) or len(line_range) != 2:  # pyright: ignore[reportUnknownArgumentType]
+            raise ToolError("`range` should be a list of two integers.")
+        start, end = line_range  # pyright: ignore[reportUnknownVariableType]
+        if not isinstance(start, int) or not isinstance(end, int):
+            raise ToolError("`range` should be a list of two integers.")
+        if start < 1:
+            raise ToolError(f"Invalid `range` {line_range}: the first line is 1.")
+        if end != -1 and end < start:
+            raise ToolError(
+                f"Invalid `range` {line_range}: end_line should be -1 or at least start_line."
+            )
+        return start, end
+
+    def _read(self, path: Path, encoding: str, line_range: tuple[int, int] | None) -> str:
+        """Decode the file, streaming past the lines before `line_range`."""
+        # newline="" keeps line endings as they are in the file
+        with open(path, encoding=encoding, newline="") as f:
+            if line_range is None:
+                content = f.read()
+            else:
+                start, end = line_range
+                lines = list(itertools.islice(f, start - 1, None if end == -1 else end))
+                if not lines and start > 1:
+                    raise ToolError(f"Invalid `range`: {path} has fewer than {start} lines.")
+                content = "".join(lines)
+        # Codecs for a given byte order keep the byte order mark as a character
+        if line_range is None or line_range[0] == 1:
+            content = content.removeprefix("\ufeff")
+        return content
@@ -27,6 +27,50 @@ def maybe_truncate(content: str, truncate_after: int | None = MAX_RESPONSE_LEN):
     )
 
 
+def truncate_middle(content: str, max_length: int) -> str:
+    """Keep the head and tail of content within max_length, replacing the middle with a marker.
+
+    Cuts are moved to line boundaries where possible, so the marker reports whole omitted lines.
+    """
+    if max_length <= 0 or len(content) <= max_length:
+        return content
+
+    head = content[: max_length // 2]
+    tail = content[len(content) - (max_length - len(head)) :]
+    if "\n" in head:
+        head = head[: head.rindex("\n") + 1]
+    if "\n" in tail:
+        tail = tail[tail.index("\n") + 1 :]
+
+    omitted = content[len(head) : len(content) - len(tail)]
+    # Only count lines when both cuts are on line boundaries, otherwise partial lines are omitted
+    on_line_boundaries = (not head or head.endswith("\n")) and omitted.endswith("\n")
+    if on_line_boundaries:
+        marker = f"[... {len(omitted.splitlines())} lines omitted ...]\n"
+    else:
+        marker = f"[... {len(omitted)} characters omitted ...]"
+    return head + marker + tail
+
+
+def truncate_output(
+    output: str | None, error: str | None, max_length: int
+) -> tuple[str | None, str | None]:
+    """Fit output and error together within max_length characters with truncate_middle.
+
+    Text shorter than its half of the budget is kept whole and leaves the rest to the other.
+    """
+    output_length, error_length = len(output or ""), len(error or "")
+    if max_length <= 0 or output_length + error_length <= max_length:
+        return output, error
+
+    error_budget = max(max_length // 2, max_length - output_length)
+    output_budget = max_length - min(error_length, error_budget)
+    return (
+        truncate_middle(output, output_budget) if output else output,
+        truncate_middle(error, error_budget) if error else error,
+    )
+
+
 async def run(
     cmd: str,
     timeout: float | None = 120.0,  # seconds
@@ -274,6 +274,10 @@ You should:
 │ {thought_data.thought.ljust(border_length - 2)} │
 └{border}┘"""
 
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
     @override
     async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
         """Execute the sequential thinking tool."""
@@ -28,6 +28,10 @@ class TaskDoneTool(Tool):
     def get_parameters(self) -> list[ToolParameter]:
         return []
 
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
     @override
     async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
         return ToolExecResult(output="Task done.")
@@ -0,0 +1,316 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tools for creating, activating and finding Python and Node virtual environments."""
+
+import os
+import re
+import shlex
+import shutil
+from pathlib import Path
+from typing import override
+
+from .base import (
+    DRY_RUN_NOTICE,
+    Tool,
+    ToolCallArguments,
+    ToolError,
+    ToolExecResult,
+    ToolParameter,
+)
+from .run import maybe_truncate, run
+
+VENV_CREATE_TIMEOUT: float = 300.0  # seconds
+# How many directory levels below the project list_venvs looks for environments
+VENV_SCAN_DEPTH: int = 3
+# Directories that never contain a project's environments and can be very large
+VENV_SCAN_SKIP: set[str] = {".git", "node_modules", "__pycache__"}
+
+PYTHON_INTERPRETER = re.compile(r"^python(\d+(\.\d+)*)?$")
+# `node`, `node18` or an exact version such as `node18.20.4`
+NODE_INTERPRETER = re.compile(r"^node(?:(\d+)((?:\.\d+){2})?)?$")
+
+
+def venv_kind(path: Path) -> str | None:
+    """Tell whether `path` is a Python venv, a nodeenv environment, or neither."""
+    if (path / "pyvenv.cfg").is_file():
+        return "python"
+    if (path / "bin" / "node").exists() and (path / "bin" / "activate").is_file():
+        return "node"
+    return None
+
+
+def python_venv_version(path: Path) -> str:
+    """Read the Python version a venv was created with from its pyvenv.cfg."""
+    try:
+        lines = (path / "pyvenv.cfg").read_text().splitlines()
+    except OSError:
+        return ""
+    for line in lines:
+        key, _, value = line.partition("=")
+        if key.strip() in ("version", "version_info"):
+            return value.strip()
+    return ""
+
+
+class CreateVenvTool(Tool):
+    """Tool to create a Python venv or a Node environment."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "create_venv"
+
+    @override
+    def get_description(self) -> str:
+        return """Create an isolated Python or Node environment for a project
+* `interpreter` picks the environment type and version: `python3`, `python3.11`, ... use `python -m venv` with that interpreter, which must be installed
+* `node` copies the installed Node into the environment, `node18` does the same if the installed Node is version 18, and an exact version such as `node18.20.4` is downloaded. Node environments are created with `nodeenv`, which must be installed
+* `path` must not exist yet or be an empty directory
+* Use `activate_venv` to get the commands that activate the environment
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Path of the environment directory to create, e.g. /repo/.venv.",
+                required=True,
+            ),
+            ToolParameter(
+                name="interpreter",
+                type="string",
+                description="Interpreter and version, e.g. `python3.11`, `python3`, `node18` or `node`.",
+                required=True,
+            ),
+        ]
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        return await self._run(arguments, dry_run=False)
+
+    @override
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report the command that would create the environment without running it."""
+        return await self._run(arguments, dry_run=True)
+
+    async def _run(self, arguments: ToolCallArguments, dry_run: bool) -> ToolExecResult:
+        try:
+            path = self.resolve_path(str(arguments.get("path", "")))
+            if not path.is_absolute():
+                raise ToolError(
+                    f"The path {path} is not an absolute path, it should start with `/`."
+                )
+            if path.exists() and (not path.is_dir() or any(path.iterdir())):
+                raise ToolError(f"The path {path} already exists and is not an empty directory.")
+            interpreter = str(arguments.get("interpreter", "")).strip().lower()
+            command = await self._create_command(path, interpreter)
+
+            if dry_run:
+                return ToolExecResult(
+                    output=f"{DRY_RUN_NOTICE} The environment was not created. The following command would be run:\n{command}"
+                )
+
+            code, stdout, stderr = await run(command, timeout=VENV_CREATE_TIMEOUT)
+            if code != 0:
+                raise ToolError(
+                    f"Creating the environment failed with exit code {code}:\n{stderr.strip() or stdout.strip()}"
+                )
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+        except (OSError, TimeoutError) as e:
+            return ToolExecResult(
+                error=f"Error creating the environment at {arguments.get('path')}: {e}",
+                error_code=-1,
+            )
+        return ToolExecResult(
+            output=f"Created a {interpreter} environment at {path}. Use `activate_venv` to activate it."
+        )
+
+    async def _create_command(self, path: Path, interpreter: str) -> str:
+        if PYTHON_INTERPRETER.match(interpreter):
+            executable = shutil.which(interpreter)
+            if executable is None:
+                raise ToolError(f"{interpreter} is not installed.")
+            return f"{shlex.quote(executable)} -m venv {shlex.quote(str(path))}"
+
+        match = NODE_INTERPRETER.match(interpreter)
+        if match is None:
+            raise ToolError(
+                f"Unsupported interpreter: {interpreter}. Use e.g. `python3.11`, `python3`, `node18` or `node`."
+            )
+        nodeenv = shutil.which("nodeenv")
+        if nodeenv is None:
+            raise ToolError(
+                "Creating Node environments needs `nodeenv`, which is not installed. Install it with `pip install nodeenv`."
+            )
+        major, exact = match.group(1), match.group(2)
+        if exact:
+            node_version = f"{major}{exact}"
+        else:
+            # nodeenv needs an exact version to download, so use the installed Node
+            if shutil.which("node") is None:
+                raise ToolError(
+                    f"Node is not installed. Install it, or give an exact version such as `node{major or 20}.0.0`."
+                )
+            if major:
+                _, installed, _ = await run("node --version")
+                installed_major = installed.strip().removeprefix("v").split(".")[0]
+                if installed_major != major:
+                    raise ToolError(
+                        f"The installed Node is {installed.strip()}, not version {major}. Give an exact version such as `node{major}.0.0` to download it."
+                    )
+            node_version = "system"
+        return f"{shlex.quote(nodeenv)} --node={node_version} {shlex.quote(str(path))}"
+
+
+class ActivateVenvTool(Tool):
+    """Tool to get the shell commands that activate an environment."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "activate_venv"
+
+    @override
+    def get_description(self) -> str:
+        return """Get the shell commands that activate a Python venv or a Node environment
+* The tool cannot change the environment of other processes itself. Run the returned commands with the `bash` tool, whose session keeps them for later commands
+* Works for environments made by `create_venv`, `python -m venv`, `virtualenv` or `nodeenv`
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Path of the environment directory.",
+                required=True,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        path = self.resolve_path(str(arguments.get("path", "")))
+        if not path.is_absolute():
+            return ToolExecResult(
+                error=f"The path {path} is not an absolute path, it should start with `/`.",
+                error_code=-1,
+            )
+        kind = venv_kind(path)
+        if kind is None:
+            return ToolExecResult(
+                error=f"No Python venv or Node environment found at {path}.", error_code=-1
+            )
+
+        quoted = shlex.quote(str(path))
+        bin_dir = shlex.quote(str(path / "bin"))
+        if kind == "python":
+            exports = [
+                f"export VIRTUAL_ENV={quoted}",
+                f'export PATH={bin_dir}:"$PATH"',
+                "unset PYTHONHOME",
+            ]
+        else:
+            exports = [
+                f"export NODE_VIRTUAL_ENV={quoted}",
+                f'export PATH={bin_dir}:"$PATH"',
+                f"export NODE_PATH={shlex.quote(str(path / 'lib' / 'node_modules'))}",
+                f"export NPM_CONFIG_PREFIX={quoted}",
+            ]
+        return ToolExecResult(
+            output=f"Run these commands to activate the environment at {path}:\n"
+            + "\n".join(exports)
+        )
+
+
+class ListVenvsTool(Tool):
+    """Tool to find the environments inside a project."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "list_venvs"
+
+    @override
+    def get_description(self) -> str:
+        return f"""List the Python venvs and Node environments in a project directory
+* Looks up to {VENV_SCAN_DEPTH} levels below `path`, including hidden directories such as .venv, but not inside .git or node_modules
+* Returns the path, type and, for Python, the version of each environment
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Path of the project directory to scan.",
+                required=True,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        root = self.resolve_path(str(arguments.get("path", "")))
+        if not root.is_absolute():
+            return ToolExecResult(
+                error=f"The path {root} is not an absolute path, it should start with `/`.",
+                error_code=-1,
+            )
+        if not root.is_dir():
+            return ToolExecResult(error=f"The path {root} is not a directory.", error_code=-1)
+
+        lines: list[str] = []
+        for dirpath, dirnames, _ in os.walk(root):
+            directory = Path(dirpath)
+            kind = venv_kind(directory)
+            if kind is not None:
+                # An environment's own packages are not the project's environments
+                dirnames.clear()
+                if kind == "python":
+                    description = f"Python {python_venv_version(directory)}".strip()
+                else:
+                    description = "Node"
+                lines.append(f"{directory} ({description})")
+                continue
+            depth = len(directory.relative_to(root).parts)
+            if depth >= VENV_SCAN_DEPTH:
+                dirnames.clear()
+            else:
+                dirnames[:] = sorted(name for name in dirnames if name not in VENV_SCAN_SKIP)
+
+        if not lines:
+            return ToolExecResult(output=f"No environments found in {root}.")
+        return ToolExecResult(
+            output=maybe_truncate(f"Environments in {root}:\n" + "\n".join(lines))
+        )
@@ -0,0 +1,190 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool that waits until a file or directory changes."""
+
+import asyncio
+import os
+from fnmatch import fnmatch
+from pathlib import Path
+from typing import override
+
+from .base import Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+from .run import maybe_truncate
+
+DEFAULT_WATCH_TIMEOUT: float = 30.0  # seconds
+MAX_WATCH_TIMEOUT: float = 600.0  # seconds
+DEFAULT_DEBOUNCE_MS: int = 200
+# Seconds between two snapshots of the watched files
+POLL_INTERVAL: float = 0.1
+
+# Modification time and size of every watched file, keyed by path relative to the watched path
+Snapshot = dict[str, tuple[int, int]]
+
+
+class WatchPathTool(Tool):
+    """Tool to block until files change, e.g. while a background build or an editor runs."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "watch_path"
+
+    @override
+    def get_description(self) -> str:
+        return f"""Wait until a file or directory changes, then list the changed files
+* Use it to react to a background build or test run, or to edits made outside the agent, instead of polling with bash
+* Directories are watched recursively, except hidden directories such as .git. A file that does not exist yet can be watched to wait for it to be created
+* Returns the changed paths with their event kind: created, modified or deleted
+* Events within `debounce_ms` of each other are reported together, so a build that writes many files returns once
+* Gives up after `timeout` seconds (default {DEFAULT_WATCH_TIMEOUT:g}, at most {MAX_WATCH_TIMEOUT:g}) and reports that nothing changed
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Absolute path to the file or directory to watch.",
+                required=True,
+            ),
+            ToolParameter(
+                name="timeout",
+                type="number",
+                description=f"Seconds to wait for a change. Defaults to {DEFAULT_WATCH_TIMEOUT:g}.",
+                required=False,
+            ),
+            ToolParameter(
+                name="debounce_ms",
+                type="integer",
+                description=f"Milliseconds without further changes before returning. Defaults to {DEFAULT_DEBOUNCE_MS}.",
+                required=False,
+            ),
+            ToolParameter(
+                name="patterns",
+                type="array",
+                description="Glob patterns such as `*.py` or `src/*.rs`, matched against file names and paths relative to `path`. Only matching files are watched.",
+                items={"type": "string"},
+                required=False,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        try:
+            path = self.resolve_path(str(arguments.get("path", "")))
+            if not path.is_absolute():
+                raise ToolError(
+                    f"The path {path} is not an absolute path, it should start with `/`."
+                )
+            if not path.exists() and not path.parent.is_dir():
+                raise ToolError(f"Neither {path} nor its parent directory exist.")
+
+            timeout = arguments.get("timeout")
+            if timeout is None:
+                timeout = DEFAULT_WATCH_TIMEOUT
+            if not isinstance(timeout, (int, float)) or not 0 < timeout <= MAX_WATCH_TIMEOUT:
+                raise ToolError(
+                    f"`timeout` should be a number of seconds between 0 and {MAX_WATCH_TIMEOUT:g}."
+                )
+            debounce_ms = arguments.get("debounce_ms")
+            if debounce_ms is None:
+                debounce_ms = DEFAULT_DEBOUNCE_MS
+            if not isinstance(debounce_ms, int) or debounce_ms < 0:
+                raise ToolError("`debounce_ms` should be a non-negative integer.")
+            patterns = arguments.get("patterns") or []
+            if not isinstance(patterns, list):
+                raise ToolError("`patterns` should be a list of glob patterns.")
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+
+        changes = await self._wait_for_changes(
+            path, float(timeout), debounce_ms / 1000, [str(pattern) for pattern in patterns]
+        )
+        if not changes:
+            return ToolExecResult(output=f"No changes to {path} within {timeout:g} seconds.")
+        lines = [f"{kind} {name}" for name, kind in sorted(changes.items())]
+        return ToolExecResult(
+            output=maybe_truncate(f"Changes to {path}:\n" + "\n".join(lines))
+        )
+
+    async def _wait_for_changes(
+        self, path: Path, timeout: float, debounce: float, patterns: list[str]
+    ) -> dict[str, str]:
+        """Poll snapshots of `path` until changes settle for `debounce` seconds or time runs out.
+
+        Nothing outlives the call: there is no watcher thread or handle to tear down.
+        """
+        loop = asyncio.get_running_loop()
+        deadline = loop.time() + timeout
+        previous = await asyncio.to_thread(self._snapshot, path, patterns)
+        changes: dict[str, str] = {}
+        last_change: float | None = None
+        while loop.time() < deadline:
+            if last_change is not None and loop.time() - last_change >= debounce:
+                break
+            await asyncio.sleep(POLL_INTERVAL)
+            current = await asyncio.to_thread(self._snapshot, path, patterns)
+            if current != previous:
+                self._merge_changes(changes, previous, current)
+                last_change = loop.time()
+            previous = current
+        return changes
+
+    def _snapshot(self, path: Path, patterns: list[str]) -> Snapshot:
+        if not path.is_dir():
+            entry = self._stat(path)
+            return {path.name: entry} if entry is not None else {}
+
+        snapshot: Snapshot = {}
+        for dirpath, dirnames, filenames in os.walk(path):
+            # Prune hidden directories in place so trees like .git are never walked
+            dirnames[:] = [name for name in dirnames if not name.startswith(".")]
+            for name in filenames:
+                file_path = Path(dirpath, name)
+                relative = file_path.relative_to(path).as_posix()
+                if patterns and not any(
+                    fnmatch(name, pattern) or fnmatch(relative, pattern) for pattern in patterns
+                ):
+                    continue
+                entry = self._stat(file_path)
+                if entry is not None:
+                    snapshot[relative] = entry
+        return snapshot
+
+    def _stat(self, path: Path) -> tuple[int, int] | None:
+        try:
+            stat = path.stat()
+        except OSError:
+            # Missing, or deleted between listing and stat
+            return None
+        return stat.st_mtime_ns, stat.st_size
+
+    def _merge_changes(self, changes: dict[str, str], previous: Snapshot, current: Snapshot):
+        """Record the events between two snapshots, folding them into earlier events."""
+        events = {name: "deleted" for name in previous.keys() - current.keys()}
+        for name, entry in current.items():
+            if name not in previous:
+                events[name] = "created"
+            elif previous[name] != entry:
+                events[name] = "modified"
+
+        for name, kind in events.items():
+            earlier = changes.get(name)
+            if earlier == "created" and kind == "deleted":
+                del changes[name]
+            elif earlier == "deleted" and kind == "created":
+                changes[name] = "modified"
+            elif earlier is None or kind == "deleted":
+                changes[name] = kind
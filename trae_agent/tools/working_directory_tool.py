@@ -0,0 +1,102 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool for changing the directory that relative tool paths resolve against."""
+
+from pathlib import Path
+from typing import override
+
+from .base import DirectoryStack, Tool, ToolCallArguments, ToolError, ToolExecResult, ToolParameter
+
+WorkingDirectorySubCommands = ["cd", "pushd", "popd", "pwd"]
+# Tools whose path arguments may be relative to the current directory
+PATH_RESOLVING_TOOLS = [
+    "str_replace_based_edit_tool",
+    "json_edit_tool",
+    "ckg",
+    "read_file",
+    "write_file",
+    "watch_path",
+    "file_hash",
+    "dependency_graph",
+]
+
+
+class WorkingDirectoryTool(Tool):
+    """Tool to move through directories like a shell, so later paths can be relative."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "working_directory"
+
+    @override
+    def get_description(self) -> str:
+        return f"""Change the current directory that relative paths resolve against, to avoid repeating long absolute paths
+* `cd` replaces the current directory, `pushd` enters a directory and remembers the previous one, `popd` returns to it, `pwd` shows where you are
+* `path` may itself be relative to the current directory, e.g. `..` or `src`
+* Relative paths given to {", ".join(PATH_RESOLVING_TOOLS)} resolve against the current directory
+* The `bash` tool keeps its own working directory; use `cd` in its commands
+* Every command returns the current directory and the directories `popd` would return to
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="command",
+                type="string",
+                description=f"The command to run. Allowed options are: {', '.join(f'`{command}`' for command in WorkingDirectorySubCommands)}.",
+                enum=WorkingDirectorySubCommands,
+                required=True,
+            ),
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Directory to change to. Required for `cd` and `pushd`.",
+                required=False,
+            ),
+        ]
+
+    @override
+    def is_read_only(self, arguments: ToolCallArguments) -> bool:
+        return True
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        if self.directory_stack is None:
+            self.directory_stack = DirectoryStack()
+        stack = self.directory_stack
+        command = str(arguments.get("command", ""))
+        try:
+            match command:
+                case "cd" | "pushd":
+                    path = arguments.get("path")
+                    if not isinstance(path, str) or not path:
+                        raise ToolError(f"Parameter `path` is required for command: {command}")
+                    if command == "cd":
+                        stack.change(Path(path))
+                    else:
+                        stack.push(Path(path))
+                case "popd":
+                    stack.pop()
+                case "pwd":
+                    pass
+                case _:
+                    raise ToolError(
+                        f"Unrecognized command {command}. The allowed commands for the {self.name} tool are: {', '.join(WorkingDirectorySubCommands)}"
+                    )
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+
+        current, *below = stack.directories
+        output = f"Current directory: {current}"
+        if below:
+            output += "\nPushed directories, most recent first:\n" + "\n".join(map(str, below))
+        return ToolExecResult(output=output)
@@ -0,0 +1,195 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Tool for writing text files in an explicit encoding."""
+
+import codecs
+import contextlib
+import difflib
+import os
+import shutil
+import tempfile
+from pathlib import Path
+from typing import override
+
+from .base import (
+    DRY_RUN_NOTICE,
+    Tool,
+    ToolCallArguments,
+    ToolError,
+    ToolExecResult,
+    ToolParameter,
+)
+from .read_file_tool import SAMPLE_SIZE, FileEncodings, detect_encoding
+
+# UTF-16 files start with a byte order mark, so that read_file can tell their byte order
+UTF16_BYTE_ORDER_MARKS: dict[str, bytes] = {
+    "utf-16-le": codecs.BOM_UTF16_LE,
+    "utf-16-be": codecs.BOM_UTF16_BE,
+}
+
+
+def encode_text(content: str, encoding: str) -> bytes:
+    """Encode `content` as it is written to a file in `encoding`."""
+    try:
+        return UTF16_BYTE_ORDER_MARKS.get(encoding, b"") + content.encode(encoding)
+    except UnicodeEncodeError as e:
+        raise ToolError(
+            f"The content cannot be written as {encoding}: {e.object[e.start : e.end]!r} at position {e.start} is not supported. Use utf-8 instead."
+        ) from None
+
+
+def write_atomic(path: Path, data: bytes) -> None:
+    """Write `data` to a temporary file next to `path` and rename it over `path`.
+
+    Readers see either the old or the new content, never a partial write, even if the process
+    dies halfway. An existing file keeps its permissions.
+    """
+    fd, temp_name = tempfile.mkstemp(dir=path.parent, prefix=f".{path.name}.", suffix=".tmp")
+    try:
+        with os.fdopen(fd, "wb") as f:
+            _ = f.write(data)
+            f.flush()
+            os.fsync(f.fileno())
+        if path.exists():
+            shutil.copymode(path, temp_name)
+        else:
+            # mkstemp creates files only their owner can read
+            os.chmod(temp_name, 0o644)
+        os.replace(temp_name, path)
+    except BaseException:
+        with contextlib.suppress(FileNotFoundError):
+            os.unlink(temp_name)
+        raise
+
+
+class WriteFileTool(Tool):
+    """Tool to write a text file in a chosen encoding."""
+
+    def __init__(self, model_provider: str | None = None) -> None:
+        super().__init__(model_provider)
+
+    @override
+    def get_model_provider(self) -> str | None:
+        return self._model_provider
+
+    @override
+    def get_name(self) -> str:
+        return "write_file"
+
+    @override
+    def get_description(self) -> str:
+        return """Write a text file, replacing its content if it exists
+* Choose the encoding with `encoding`: utf-8 (default), utf-16-le, utf-16-be or latin-1. To keep a file's encoding, use the one `read_file` reported. UTF-16 files get a byte order mark
+* Set `create_dirs` to true to create missing parent directories
+* Set `atomic` to true to write a temporary file and rename it into place, so the file is never left half written
+* To change a few lines of a file, prefer `str_replace_based_edit_tool`
+"""
+
+    @override
+    def get_parameters(self) -> list[ToolParameter]:
+        return [
+            ToolParameter(
+                name="path",
+                type="string",
+                description="Absolute path to the file to write.",
+                required=True,
+            ),
+            ToolParameter(
+                name="content",
+                type="string",
+                description="The full new content of the file.",
+                required=True,
+            ),
+            ToolParameter(
+                name="encoding",
+                type="string",
+                description="Encoding to write the file in. Defaults to utf-8.",
+                enum=FileEncodings,
+                required=False,
+            ),
+            ToolParameter(
+                name="create_dirs",
+                type="boolean",
+                description="Create missing parent directories. Defaults to false.",
+                required=False,
+            ),
+            ToolParameter(
+                name="atomic",
+                type="boolean",
+                description="Write to a temporary file and rename it into place. Defaults to false.",
+                required=False,
+            ),
+        ]
+
+    @override
+    async def execute(self, arguments: ToolCallArguments) -> ToolExecResult:
+        return await self._run(arguments, dry_run=False)
+
+    @override
+    async def dry_run(self, arguments: ToolCallArguments) -> ToolExecResult:
+        """Report the diff the write would make without writing the file."""
+        return await self._run(arguments, dry_run=True)
+
+    async def _run(self, arguments: ToolCallArguments, dry_run: bool) -> ToolExecResult:
+        try:
+            path = self.resolve_path(str(arguments.get("path", "")))
+            if not path.is_absolute():
+                raise ToolError(
+                    f"The path {path} is not an absolute path, it should start with `/`."
+                )
+            if path.is_dir():
+                raise ToolError(f"The path {path} is a directory.")
+            content = arguments.get("content")
+            if not isinstance(content, str):
+                raise ToolError("Parameter `content` is required and should be a string.")
+            encoding = str(arguments.get("encoding") or "utf-8").lower()
+            if encoding not in FileEncodings:
+                raise ToolError(
+                    f"Unsupported encoding: {encoding}. Supported encodings: {', '.join(FileEncodings)}"
+                )
+            data = encode_text(content, encoding)
+
+            if not path.parent.is_dir() and arguments.get("create_dirs") is not True:
+                raise ToolError(
+                    f"The directory {path.parent} does not exist. Set `create_dirs` to true to create it."
+                )
+            if dry_run:
+                return self._dry_run_result(path, content, encoding)
+
+            path.parent.mkdir(parents=True, exist_ok=True)
+
+            if arguments.get("atomic") is True:
+                write_atomic(path, data)
+            else:
+                _ = path.write_bytes(data)
+        except ToolError as e:
+            return ToolExecResult(error=str(e), error_code=-1)
+        except OSError as e:
+            return ToolExecResult(
+                error=f"Error writing {arguments.get('path')}: {e}", error_code=-1
+            )
+        return ToolExecResult(output=f"Wrote {len(data)} bytes to {path} ({encoding}).")
+
+    def _dry_run_result(self, path: Path, content: str, encoding: str) -> ToolExecResult:
+        """Describe the change writing `content` to `path` would make, without applying it."""
+        old_lines: list[str] = []
+        if path.is_file():
+            data = path.read_bytes()
+            try:
+                old_text = data.decode(detect_encoding(data[:SAMPLE_SIZE]))
+                old_lines = old_text.removeprefix("\ufeff").split("\n")
+            except (ToolError, UnicodeDecodeError):
+                old_lines = [f"<{len(data)} bytes of binary content>"]
+        diff = "\n".join(
+            difflib.unified_diff(
+                old_lines,
+                content.split("\n"),
+                fromfile=str(path) if path.exists() else "/dev/null",
+                tofile=str(path),
+                lineterm="",
+            )
+        )
+        return ToolExecResult(
+            output=f"{DRY_RUN_NOTICE} The following change to {path} ({encoding}) would be made:\n{diff}\n"
+        )
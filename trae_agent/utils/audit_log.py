@@ -0,0 +1,118 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Append-only audit log of the tool calls made across all runs."""
+
+import json
+import re
+from datetime import datetime
+from pathlib import Path
+
+from ..tools.base import ToolCall, ToolResult
+from .constants import LOCAL_STORAGE_PATH
+
+# One log per user, shared by runs started from any directory
+DEFAULT_AUDIT_LOG_PATH: Path = LOCAL_STORAGE_PATH / "audit.jsonl"
+REDACTED: str = "[REDACTED]"
+# Argument, variable, header and flag names that likely hold credentials. `token` only counts
+# as a whole word, so `page_token` and `max_tokens` stay readable
+SENSITIVE_NAME_PATTERN = re.compile(
+    r"passw(or)?d|passphrase|secret|api[_-]?key|access[_-]?key|private[_-]?key"
+    r"|credential|authorization|cookie|(^|(?<!page)[_-])token$",
+    re.IGNORECASE,
+)
+# A quoted or bare value in a command line
+VALUE_PATTERN = r"""('[^']*'|"[^"]*"|[^\s'"]+)"""
+# `NAME=value` assignments inside strings, e.g. in a bash command or its `env` entries
+ASSIGNMENT_PATTERN = re.compile(r"\b([A-Za-z_][A-Za-z0-9_-]*)=" + VALUE_PATTERN)
+# `Name: value` headers, e.g. `curl -H 'Authorization: Bearer abc'`; the scheme is kept
+HEADER_PATTERN = re.compile(
+    r"\b([A-Za-z][A-Za-z0-9-]*):[ \t]*((?:Basic|Bearer|Digest|Token)[ \t]+)?([^'\"\r\n]+)",
+    re.IGNORECASE,
+)
+# Space-separated `--name value` flags, e.g. `--token abc`
+FLAG_PATTERN = re.compile(r"(?<![\w-])(--?[A-Za-z][A-Za-z0-9_-]*)([ \t]+)(?!-)" + VALUE_PATTERN)
+
+
+def _redact_string(value: str) -> str:
+    """Redact the values of sensitive assignments, headers and flags in a string."""
+    value = ASSIGNMENT_PATTERN.sub(
+        lambda match: f"{match.group(1)}={REDACTED}"
+        if SENSITIVE_NAME_PATTERN.search(match.group(1))
+        else match.group(0),
+        value,
+    )
+    value = HEADER_PATTERN.sub(
+        lambda match: f"{match.group(1)}: {match.group(2) or ''}{REDACTED}"
+        if SENSITIVE_NAME_PATTERN.search(match.group(1))
+        else match.group(0),
+        value,
+    )
+    return FLAG_PATTERN.sub(
+        lambda match: f"{match.group(1)}{match.group(2)}{REDACTED}"
+        if SENSITIVE_NAME_PATTERN.search(match.group(1).lstrip("-"))
+        else match.group(0),
+        value,
+    )
+
+
+def redact(value: object) -> object:
+    """Replace the values of sensitive-looking keys, and of such assignments, headers and flags.
+
+    Dicts and lists are redacted recursively; other values are returned unchanged.
+    """
+    if isinstance(value, dict):
+        return {
+            key: REDACTED if SENSITIVE_NAME_PATTERN.search(str(key)) else redact(item)
+            for key, item in value.items()
+        }
+    if isinstance(value, list):
+        return [redact(item) for item in value]
+    if isinstance(value, str):
+        return _redact_string(value)
+    return value
+
+
+class AuditLog:
+    """Appends one JSON line per tool call to a file shared by all runs.
+
+    Each entry holds the timestamp, session id, tool name, redacted arguments and whether the
+    call succeeded. Entries are never rewritten.
+    """
+
+    def __init__(self, path: str | Path = DEFAULT_AUDIT_LOG_PATH):
+        """Initialize the audit log.
+
+        Args:
+            path: Path of the JSONL file. Relative paths are resolved against the current
+                directory at creation time, so a later change of directory does not split the log.
+        """
+        self.path: Path = Path(path).expanduser().absolute()
+        self._warned: bool = False
+
+    def record(
+        self, tool_call: ToolCall, result: ToolResult, session_id: str | None = None
+    ) -> None:
+        """Append an entry for a finished tool call.
+
+        Args:
+            tool_call: The call as requested by the model
+            result: The result of the call
+            session_id: Identifier of the run, e.g. the trajectory file name
+        """
+        entry = {
+            "timestamp": datetime.now().isoformat(),
+            "session_id": session_id,
+            "tool": tool_call.name,
+            "arguments": redact(tool_call.arguments),
+            "success": result.success,
+        }
+        try:
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+            with open(self.path, "a", encoding="utf-8") as f:
+                _ = f.write(json.dumps(entry, ensure_ascii=False) + "\n")
+        except OSError as e:
+            # Tool calls go on, but the gap in the log is reported once per run
+            if not self._warned:
+                self._warned = True
+                print(f"Warning: Could not write audit log {self.path}: {e}")
@@ -6,10 +6,13 @@ from dataclasses import dataclass
 
 from rich.console import Console, Group
 from rich.live import Live
+from rich.markup import escape
 from rich.panel import Panel
+from rich.prompt import Confirm
 from rich.table import Table
 
 from ..agent.agent_basics import AgentExecution, AgentState, AgentStep
+from ..tools.base import ToolCall
 from .config import Config, LakeviewConfig
 from .lake_view import LakeView
 
@@ -110,6 +113,28 @@ class CLIConsole:
         message = f"[{color}]{message}[/{color}]"
         self.console.print(message)
 
+    def confirm_tool_call(self, tool_call: ToolCall) -> bool:
+        """Ask the user whether a tool call may proceed. Defaults to no."""
+        # The live display would redraw over the prompt; the next progress update restarts it
+        if self.live_display is not None:
+            self.live_display.stop()
+            self.live_display = None
+
+        self.console.print(
+            Panel(
+                "\n".join(
+                    f"[bold]{key}:[/bold] {escape(str(value))}"
+                    for key, value in tool_call.arguments.items()
+                ),
+                title=f"Confirm tool call: {tool_call.name}",
+                border_style="yellow",
+            )
+        )
+        try:
+            return Confirm.ask("Allow this tool call?", console=self.console, default=False)
+        except EOFError:
+            return False
+
     def _create_compact_step_display(self, agent_step: AgentStep):
         step_content: list[str] = []
         color, emoji = AGENT_STATE_INFO.get(agent_step.state, ("white", "❓"))
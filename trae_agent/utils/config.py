@@ -10,10 +10,14 @@
 
 import json
 import os
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 from pathlib import Path
 from typing import Any, override
 
+from ..tools.base import is_dry_run
+from ..tools.run import MAX_RESPONSE_LEN
+from .audit_log import DEFAULT_AUDIT_LOG_PATH
+
 
 # data class for model parameters
 @dataclass
@@ -51,6 +55,10 @@ class Config:
     model_providers: dict[str, ModelParameters]
     lakeview_config: LakeviewConfig | None = None
     enable_lakeview: bool = True
+    confirm_tools: list[str] = field(default_factory=list)
+    max_tool_output: int = MAX_RESPONSE_LEN
+    audit_log: str = str(DEFAULT_AUDIT_LOG_PATH)
+    dry_run: bool = False
 
     def __init__(self, config_or_config_file: str | dict = "trae_config.json"):  # pyright: ignore[reportMissingTypeArgument, reportUnknownParameterType]
         # Accept either file path or direct config dict
@@ -72,6 +80,10 @@ class Config:
         self.max_steps = self._config.get("max_steps", 20)
         self.model_providers = {}
         self.enable_lakeview = self._config.get("enable_lakeview", True)
+        self.confirm_tools = list(self._config.get("confirm_tools", []))
+        self.max_tool_output = int(self._config.get("max_tool_output", MAX_RESPONSE_LEN))
+        self.audit_log = str(self._config.get("audit_log", DEFAULT_AUDIT_LOG_PATH) or "")
+        self.dry_run = bool(self._config.get("dry_run", False)) or is_dry_run()
 
         if len(self._config.get("model_providers", [])) == 0:
             self.model_providers = {
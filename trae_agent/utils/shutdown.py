@@ -0,0 +1,104 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Graceful shutdown on SIGINT and SIGTERM."""
+
+import asyncio
+import inspect
+import os
+import signal
+import sys
+import threading
+import time
+from collections.abc import Callable, Coroutine
+from types import FrameType
+from typing import Any, TypeAlias
+
+# Cleanup run at shutdown, either a plain function or a coroutine function
+ShutdownHook: TypeAlias = Callable[[], None] | Callable[[], Coroutine[Any, Any, None]]
+SignalHandler: TypeAlias = Callable[[int, FrameType | None], Any] | int | None
+
+DEFAULT_SHUTDOWN_DEADLINE: float = 5.0  # seconds
+# Exit code when the hooks did not finish within the deadline
+FORCED_EXIT_CODE: int = 1
+# A second interrupt within this window skips cleanup and exits immediately
+FORCE_EXIT_WINDOW: float = 2.0  # seconds
+SHUTDOWN_SIGNALS: list[signal.Signals] = [signal.SIGINT, signal.SIGTERM]
+
+
+class ShutdownCoordinator:
+    """Run cleanup hooks in order when the process is asked to stop.
+
+    Once installed, the first SIGINT or SIGTERM raises KeyboardInterrupt, so both signals unwind
+    the interrupted code the same way. The code that catches it then calls `shutdown`, which runs
+    the registered hooks in registration order. If they take longer than the deadline, the
+    process exits with FORCED_EXIT_CODE without waiting for them. Later signals do not raise
+    again, so they cannot abort the cleanup halfway, but one within FORCE_EXIT_WINDOW seconds of
+    the previous signal exits immediately with 128 + the signal number.
+    """
+
+    def __init__(self, deadline: float = DEFAULT_SHUTDOWN_DEADLINE):
+        self._deadline: float = deadline
+        self._hooks: list[tuple[str, ShutdownHook]] = []
+        self._previous_handlers: dict[signal.Signals, SignalHandler] = {}
+        self._last_signal_time: float | None = None
+        # The first signal received since the coordinator was created or reset
+        self.received_signal: int | None = None
+
+    def register(self, name: str, hook: ShutdownHook) -> None:
+        """Add a hook to run at shutdown, after the hooks registered before it."""
+        self._hooks.append((name, hook))
+
+    def reset(self) -> None:
+        """Drop the registered hooks and forget earlier signals, e.g. between tasks of a session."""
+        self._hooks.clear()
+        self._last_signal_time = None
+        self.received_signal = None
+
+    def install(self) -> None:
+        """Handle SIGINT and SIGTERM until `uninstall` is called."""
+        for signum in SHUTDOWN_SIGNALS:
+            self._previous_handlers[signum] = signal.signal(signum, self._handle_signal)
+
+    def uninstall(self) -> None:
+        """Restore the signal handlers that were in place before `install`."""
+        for signum, handler in self._previous_handlers.items():
+            _ = signal.signal(signum, handler)
+        self._previous_handlers.clear()
+
+    def _handle_signal(self, signum: int, frame: FrameType | None) -> None:  # pyright: ignore[reportUnusedParameter]
+        now = time.monotonic()
+        if self._last_signal_time is not None and now - self._last_signal_time < FORCE_EXIT_WINDOW:
+            os._exit(128 + signum)
+        interrupted = self._last_signal_time is not None
+        self._last_signal_time = now
+        if self.received_signal is None:
+            self.received_signal = signum
+        # Later signals only arm the forced exit, raising again would abort the cleanup halfway
+        if not interrupted:
+            raise KeyboardInterrupt
+
+    def shutdown(self) -> None:
+        """Run the hooks in registration order, exiting the process if the deadline passes.
+
+        A failing hook is reported on stderr and does not stop the hooks after it.
+        """
+        watchdog = threading.Timer(self._deadline, self._force_exit)
+        watchdog.daemon = True
+        watchdog.start()
+        try:
+            for name, hook in self._hooks:
+                try:
+                    result = hook()
+                    if inspect.iscoroutine(result):
+                        asyncio.run(result)
+                except Exception as e:
+                    print(f"Shutdown hook '{name}' failed: {e}", file=sys.stderr)
+        finally:
+            watchdog.cancel()
+
+    def _force_exit(self) -> None:
+        print(
+            f"Cleanup did not finish within {self._deadline:g} seconds, exiting", file=sys.stderr
+        )
+        os._exit(FORCED_EXIT_CODE)
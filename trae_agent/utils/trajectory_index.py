@@ -0,0 +1,119 @@
+# Copyright (c) 2025 ByteDance Ltd. and/or its affiliates
+# SPDX-License-Identifier: MIT
+
+"""Cached inverted index over the messages of stored trajectories, used by `trae-cli search`."""
+
+import json
+import re
+from pathlib import Path
+
+from .trajectory_recorder import TrajectoryRecorder
+
+INDEX_FILE_NAME: str = ".search_index.json"
+INDEX_VERSION: int = 1
+TERM_PATTERN = re.compile(r"\w+")
+# Raised by TrajectoryRecorder.load for JSON files that are not trajectories
+NOT_A_TRAJECTORY_ERRORS = (json.JSONDecodeError, UnicodeDecodeError, AttributeError, TypeError)
+
+
+def tokenize(text: str) -> set[str]:
+    """Split text into the lowercase terms the index is keyed on."""
+    return set(TERM_PATTERN.findall(text.lower()))
+
+
+class TrajectoryIndex:
+    """Inverted term map of the trajectory files in a directory.
+
+    The index is cached in the directory itself. Only files whose modification time or size
+    changed since the last refresh are read again.
+    """
+
+    def __init__(self, directory: str | Path):
+        """Initialize the index, loading the cached copy if there is one.
+
+        Args:
+            directory: Directory containing the trajectory files
+        """
+        self.directory: Path = Path(directory)
+        self.index_path: Path = self.directory / INDEX_FILE_NAME
+        # File name -> [mtime_ns, size] of the version that was indexed
+        self._files: dict[str, list[int]] = {}
+        # Term -> names of the files whose messages contain it
+        self._terms: dict[str, set[str]] = {}
+        self._load()
+
+    def _load(self) -> None:
+        try:
+            with open(self.index_path, "r", encoding="utf-8") as f:
+                data = json.load(f)
+            if data.get("version") != INDEX_VERSION:
+                return
+            self._files = data["files"]
+            self._terms = {term: set(names) for term, names in data["terms"].items()}
+        except (OSError, json.JSONDecodeError, AttributeError, KeyError, TypeError):
+            # Missing or unreadable cache, it is rebuilt on refresh
+            self._files, self._terms = {}, {}
+
+    def _save(self) -> None:
+        data = {
+            "version": INDEX_VERSION,
+            "files": self._files,
+            "terms": {term: sorted(names) for term, names in self._terms.items()},
+        }
+        try:
+            with open(self.index_path, "w", encoding="utf-8") as f:
+                json.dump(data, f)
+        except OSError:
+            # A read-only directory can still be searched, just without caching
+            pass
+
+    def trajectory_files(self) -> list[Path]:
+        """List the trajectory files of the directory."""
+        return sorted(
+            path for path in self.directory.glob("*.json") if not path.name.startswith(".")
+        )
+
+    def refresh(self) -> None:
+        """Re-index new and changed trajectory files and forget deleted ones."""
+        current: dict[str, list[int]] = {}
+        for path in self.trajectory_files():
+            stat = path.stat()
+            current[path.name] = [stat.st_mtime_ns, stat.st_size]
+
+        outdated = {name for name, indexed in self._files.items() if current.get(name) != indexed}
+        added = {name for name, signature in current.items() if self._files.get(name) != signature}
+        if not outdated and not added:
+            return
+
+        for term in list(self._terms):
+            self._terms[term] -= outdated
+            if not self._terms[term]:
+                del self._terms[term]
+        for name in outdated:
+            del self._files[name]
+
+        for name in added:
+            self._files[name] = current[name]
+            try:
+                messages = TrajectoryRecorder.load(str(self.directory / name)).get_messages()
+            except NOT_A_TRAJECTORY_ERRORS:
+                # Not a trajectory file; remembered without terms so it is not parsed again
+                continue
+            for message in messages:
+                for term in tokenize(str(message["content"] or "")):
+                    self._terms.setdefault(term, set()).add(name)
+
+        self._save()
+
+    def candidates(self, query: str) -> list[Path]:
+        """List the files that may contain query; files without any of its terms are skipped.
+
+        A case-insensitive substring match of query implies every term of query is a substring
+        of a term of the message, so no matching file is ever left out.
+        """
+        names = set(self._files)
+        for query_term in tokenize(query):
+            names &= {
+                name for term, files in self._terms.items() if query_term in term for name in files
+            }
+        return [self.directory / name for name in sorted(names)]
@@ -9,6 +9,7 @@
 """Trajectory recording functionality for Trae Agent."""
 
 import json
+import re
 from datetime import datetime
 from pathlib import Path
 from typing import Any
@@ -251,3 +252,147 @@ class TrajectoryRecorder:
     def get_trajectory_path(self) -> str:
         """Get the path where trajectory is being saved."""
         return str(self.trajectory_path)
+
+    @classmethod
+    def load(cls, trajectory_path: str) -> "TrajectoryRecorder":
+        """Load a previously saved trajectory file.
+
+        Args:
+            trajectory_path: Path of the trajectory file to load
+        """
+        recorder = cls(trajectory_path)
+        with open(recorder.trajectory_path, "r", encoding="utf-8") as f:
+            recorder.trajectory_data = json.load(f)
+        return recorder
+
+    def get_tool_call_records(self) -> list[dict[str, Any]]:
+        """Pair every recorded tool call with its result, in execution order."""
+        records: list[dict[str, Any]] = []
+        for step in self.trajectory_data["agent_steps"]:
+            results = {result["call_id"]: result for result in step.get("tool_results") or []}
+            for tool_call in step.get("tool_calls") or []:
+                result = results.get(tool_call["call_id"], {})
+                records.append(
+                    {
+                        "step_number": step["step_number"],
+                        "name": tool_call["name"],
+                        "arguments": tool_call["arguments"],
+                        "success": result.get("success"),
+                        "result": result.get("result"),
+                        "error": result.get("error"),
+                    }
+                )
+        return records
+
+    def get_messages(self) -> list[dict[str, Any]]:
+        """List the conversation of the trajectory: the task, then each step's response.
+
+        Returns:
+            Dicts with the role ("user" or "assistant"), content and ISO timestamp of each message.
+        """
+        messages: list[dict[str, Any]] = [
+            {
+                "role": "user",
+                "content": self.trajectory_data.get("task", ""),
+                "timestamp": self.trajectory_data.get("start_time", ""),
+            }
+        ]
+        for step in self.trajectory_data.get("agent_steps", []):
+            llm_response = step.get("llm_response") or {}
+            if llm_response.get("content"):
+                messages.append(
+                    {
+                        "role": "assistant",
+                        "content": llm_response["content"],
+                        "timestamp": step.get("timestamp", ""),
+                    }
+                )
+        return messages
+
+    def export_tool_calls(self, path: str | Path) -> int:
+        """Write all tool invocations of the trajectory to a JSONL file.
+
+        Args:
+            path: Path of the JSONL file to write
+
+        Returns:
+            The number of tool calls written.
+        """
+        records = self.get_tool_call_records()
+        export_path = Path(path)
+        export_path.parent.mkdir(parents=True, exist_ok=True)
+        with open(export_path, "w", encoding="utf-8") as f:
+            for record in records:
+                _ = f.write(json.dumps(record, ensure_ascii=False) + "\n")
+        return len(records)
+
+    def export_markdown(self, path: str | Path) -> None:
+        """Write the trajectory as a readable Markdown document.
+
+        The task becomes a `## User` section and every agent step an `## Assistant` section.
+        Tool calls are fenced code blocks tagged with the tool name, followed by their results.
+
+        Args:
+            path: Path of the Markdown file to write
+        """
+        data = self.trajectory_data
+        lines: list[str] = [
+            "---",
+            f"trajectory_id: {json.dumps(self.trajectory_path.stem)}",
+            f"start_time: {json.dumps(data.get('start_time', ''))}",
+            f"provider: {json.dumps(data.get('provider', ''))}",
+            f"model: {json.dumps(data.get('model', ''))}",
+            "---",
+            "",
+            "## User",
+            "",
+            data.get("task", ""),
+            "",
+        ]
+
+        for step in data.get("agent_steps", []):
+            lines += ["## Assistant", ""]
+            llm_response = step.get("llm_response") or {}
+            if llm_response.get("content"):
+                lines += [llm_response["content"], ""]
+
+            results = {result["call_id"]: result for result in step.get("tool_results") or []}
+            for tool_call in step.get("tool_calls") or []:
+                lines += [
+                    self._markdown_fence(
+                        json.dumps(tool_call["arguments"], indent=2, ensure_ascii=False),
+                        tool_call["name"],
+                    ),
+                    "",
+                ]
+                result = results.get(tool_call["call_id"])
+                if result is not None:
+                    status = "Result" if result.get("success") else "Error"
+                    output = result.get("result") or result.get("error") or ""
+                    lines += [f"**{status}:**", "", self._markdown_fence(output), ""]
+
+            if step.get("reflection"):
+                lines += [f"> {step['reflection']}", ""]
+            if step.get("error"):
+                lines += [f"**Error:** {step['error']}", ""]
+
+        if data.get("end_time"):
+            lines += [
+                "## Result",
+                "",
+                f"Success: {'yes' if data.get('success') else 'no'}",
+                "",
+            ]
+            if data.get("final_result"):
+                lines += [data["final_result"], ""]
+
+        export_path = Path(path)
+        export_path.parent.mkdir(parents=True, exist_ok=True)
+        _ = export_path.write_text("\n".join(lines), encoding="utf-8")
+
+    @staticmethod
+    def _markdown_fence(text: str, language: str = "") -> str:
+        """Wrap text in a code fence longer than any backtick run that could close it."""
+        longest_run = max((len(run) for run in re.findall(r"^\s*(`+)", text, re.M)), default=0)
+        fence = "`" * max(3, longest_run + 1)
+        return f"{fence}{language}\n{text}\n{fence}"